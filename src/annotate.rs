@@ -0,0 +1,51 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context as _};
+use clap::Parser;
+use saphyr::Yaml;
+
+use crate::stcm2::{self, Stcm2};
+
+#[derive(Parser)]
+pub struct Args {
+    input: PathBuf,
+    #[arg(help = "YAML mapping of addresses, export names or fn_/local_ autolabels to descriptive names")]
+    annotations: PathBuf,
+    output: PathBuf
+}
+
+// Keys can be an address, an autolabel as disasm prints it, or an existing export name
+fn resolve(stcm2: &Stcm2, key: &Yaml<'_>) -> anyhow::Result<u32> {
+    if let Some(addr) = key.as_integer() {
+        return addr.try_into().with_context(|| format!("address {addr:X} out of range"))
+    }
+    let Some(name) = key.as_str() else {
+        bail!("key {key:?} is neither an address nor a name")
+    };
+    if let Some(hex) = name.strip_prefix("fn_").or_else(|| name.strip_prefix("local_"))
+        && let Ok(addr) = u32::from_str_radix(hex, 16)
+        && stcm2.actions.contains_key(&addr)
+    {
+        return Ok(addr)
+    }
+    stcm2.actions.iter().find(|(_, act)| act.label() == Some(name.as_bytes())).map(|(&addr, _)| addr)
+        .with_context(|| format!("no export named {name}"))
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let mut stcm2 = stcm2::from_bytes(fs::read(&args.input)?.into()).with_context(|| format!("failed to parse {}", args.input.display()))?;
+    let annotations = super::load_config(&args.annotations).with_context(|| format!("failed to load {}", args.annotations.display()))?;
+
+    for (key, value) in annotations.as_mapping().context("annotations are not a mapping")? {
+        let addr = resolve(&stcm2, key)?;
+        let desc = value.as_str().with_context(|| format!("annotation {value:?} is not a str"))?;
+        // the address keeps names unique and points back at the original
+        let name = format!("{desc}_{addr:X}");
+        stcm2.set_export(addr, name.as_bytes()).with_context(|| format!("annotating action at {addr:06X}"))?;
+    }
+
+    let out = stcm2::to_bytes(&stcm2, stcm2.variant.filler())?;
+    fs::write(args.output, out)?;
+
+    Ok(())
+}
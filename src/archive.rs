@@ -0,0 +1,100 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, ensure, Context as _};
+use bytes::{Buf as _, BufMut as _};
+use clap::Parser;
+use saphyr::Yaml;
+
+// The only built-in archive format ("flatpak"): a u32 file count, then for each file a u32 offset,
+// a u32 length and a 32-byte null-padded name, then the files' contents back to back.
+// Games with their own archive formats need those added as further archive_format values.
+const NAME_LENGTH: usize = 32;
+const ENTRY_LENGTH: usize = 8 + NAME_LENGTH;
+
+#[derive(Parser)]
+pub struct PackArgs {
+    #[arg(help = "directory whose files are packed, in name order")]
+    dir: PathBuf,
+    output: PathBuf
+}
+
+#[derive(Parser)]
+pub struct UnpackArgs {
+    input: PathBuf,
+    dir: PathBuf
+}
+
+fn check_format(confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    match super::config_get(confs, "archive_format").map(|f| f.as_str().context("archive_format is not a str")).transpose()? {
+        None | Some("flatpak") => Ok(()),
+        Some(other) => bail!("unsupported archive_format {other:?} (only flatpak is built in)")
+    }
+}
+
+pub fn pack(args: PackArgs, confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    check_format(confs)?;
+
+    let mut files = fs::read_dir(&args.dir)?
+        .map(|ent| Ok(ent?.path()))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    files.retain(|path| path.is_file());
+    files.sort();
+
+    let mut out = Vec::new();
+    out.put_u32_le(u32::try_from(files.len())?);
+    let mut offset = 4 + ENTRY_LENGTH * files.len();
+    let mut contents = Vec::new();
+    for path in &files {
+        let name = path.file_name().context("no file name")?.to_str().with_context(|| format!("{} is not UTF-8", path.display()))?;
+        ensure!(name.len() <= NAME_LENGTH, "file name {name} is longer than {NAME_LENGTH} bytes");
+        let data = fs::read(path)?;
+
+        out.put_u32_le(u32::try_from(offset)?);
+        out.put_u32_le(u32::try_from(data.len())?);
+        out.put_slice(name.as_bytes());
+        out.put_bytes(0, NAME_LENGTH - name.len());
+
+        offset += data.len();
+        contents.push(data);
+    }
+    for data in contents {
+        out.put_slice(&data);
+    }
+
+    println!("packed {} files, {} bytes", files.len(), out.len());
+    fs::write(args.output, out)?;
+
+    Ok(())
+}
+
+pub fn unpack(args: UnpackArgs, confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    check_format(confs)?;
+
+    let archive = fs::read(&args.input)?;
+    let mut header = &archive[..];
+    ensure!(header.len() >= 4, "archive is too short for a header");
+    let count = usize::try_from(header.get_u32_le())?;
+    ensure!(header.len() >= count * ENTRY_LENGTH, "archive is too short for {count} entries");
+
+    fs::create_dir_all(&args.dir)?;
+    for i in 0..count {
+        let offset = usize::try_from(header.get_u32_le())?;
+        let len = usize::try_from(header.get_u32_le())?;
+        let name = &header[..NAME_LENGTH];
+        header.advance(NAME_LENGTH);
+
+        let name = name.split(|&b| b == 0).next().unwrap_or_default();
+        // fall back to a numbered name rather than trusting a path from the archive
+        let name = match str::from_utf8(name) {
+            Ok(name) if !name.is_empty() && !name.contains(['/', '\\']) && name != "." && name != ".." => name.to_owned(),
+            _ => format!("{i:04}.dat")
+        };
+
+        let data = archive.get(offset..offset + len).with_context(|| format!("entry {i} ({name}) runs past the end of the archive"))?;
+        fs::write(args.dir.join(&name), data)?;
+    }
+
+    println!("unpacked {count} files");
+
+    Ok(())
+}
@@ -1,122 +1,88 @@
-use std::{borrow::Cow, fs::{self, File}, io::{self, BufRead, BufReader}, mem, path::PathBuf, ptr, sync::LazyLock};
+use std::{borrow::Cow, collections::{BTreeSet, HashMap}, fmt::{self, Write as _}, fs::{self, File}, io::{BufRead, BufReader}, iter, path::{Path, PathBuf}, sync::{mpsc, LazyLock}, time::{Duration, Instant, SystemTime, UNIX_EPOCH}};
 
-use anyhow::{bail, ensure, Context as _};
+use anyhow::{anyhow, bail, ensure, Context as _};
 use bimap::BiMap;
 use bstr::BStr;
-use bytes::{BufMut, Bytes};
-use clap::Parser;
+use bytes::Bytes;
+use clap::{Parser, ValueEnum as _};
 use indexmap::IndexMap;
-use regex::{Captures, Regex};
+use notify::{RecursiveMode, Watcher as _};
+use regex::Regex;
+use saphyr::Yaml;
 use base64::prelude::*;
 
-use crate::stcm2::{Action, Parameter, CODE_START_MAGIC, EXPORT_DATA_MAGIC, GLOBAL_DATA_MAGIC, GLOBAL_DATA_OFFSET, STCM2_MAGIC, STCM2_TAG_LENGTH, COLLECTION_LINK_MAGIC};
+use crate::stcm2::{self, encode_bytestring, encode_string, Action, Parameter, Stcm2, Variant};
 
 #[derive(Parser)]
 pub struct Args {
+    #[arg(long, help = "only check whether the output file would change; exit with an error if so")]
+    check: bool,
+    #[arg(long, num_args = 2, value_names = ["OLD", "NEW"], help = "renumber opcodes from an old config.yaml's mnemonics to a new one's")]
+    remap_opcodes: Option<Vec<PathBuf>>,
+    #[arg(long, help = "cut export names at the first null and require a null terminator within the export name field")]
+    null_terminate_exports: bool,
+    #[arg(long, value_name = "N", default_value_t = 1, help = "keep going after an error and report up to N of them")]
+    max_errors: usize,
+    #[arg(long, value_enum, help = "file variant to write (default: STCM2L if the tag starts with L, else STCM2)")]
+    output_format: Option<Variant>,
+    #[arg(long, value_name = "HEX", value_parser = parse_hex, conflicts_with = "output_format",
+        help = "write this filler word in parameters instead of the variant's (STCM2 uses ff000000, STCM2L 40000000)")]
+    force_filler: Option<u32>,
+    #[arg(long, value_name = "ORIGINAL",
+        help = "fail unless the output is byte-identical to ORIGINAL, warning first if ORIGINAL uses a different filler")]
+    verify_roundtrip: Option<PathBuf>,
+    #[arg(long, help = "print the size of each section of the output")]
+    emit_sizes: bool,
+    #[arg(long, num_args = 1.., value_name = "FILE", help = "append the code of more source files (after their .code_start), resolving labels across all of them")]
+    concat: Vec<PathBuf>,
+    #[arg(long, help = "replace junk data (! ...) with as many zero bytes, keeping every action the same size")]
+    strip_junk: bool,
+    #[arg(long, help = "print how long each phase took to stderr")]
+    timing: bool,
+    #[arg(long, conflicts_with = "check", help = "keep running and reassemble whenever a source file changes")]
+    watch: bool,
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "ENCODINGS",
+        help = "encodings to try, in order, for strings that the main encoding can't represent")]
+    encoding_fallback: Vec<super::Encoding>,
     #[arg(from_global)]
-    encoding: super::Encoding,
+    encoding: Option<super::Encoding>,
     input: PathBuf,
     output: PathBuf
 }
 
-fn decode_label(label: &str) -> Cow<'_, [u8]> {
-    use regex::bytes::*;
-
-    // note: this is a bytes regex
-    static PLACEHOLDER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\\x([0-9a-f]{2})").unwrap());
-    PLACEHOLDER.replace_all(label.as_bytes(), |capt: &Captures<'_>| {
-        [u8::from_str_radix(
-            str::from_utf8(capt.get(1).unwrap().as_bytes()).unwrap(),
-            16
-        ).unwrap()]
-    })
+pub fn parse_hex(s: &str) -> Result<u32, std::num::ParseIntError> {
+    u32::from_str_radix(s.trim_start_matches("0x"), 16)
 }
 
-fn cow_str_to_bytes<'a>(encoding: &'static encoding_rs::Encoding, s: Cow<'a, str>) -> Cow<'a, [u8]> {
-    match s {
-        Cow::Borrowed(s) => {
-            let (s, _, replaced) = encoding.encode(s);
-            if replaced { println!("warning: encountered unmappable character"); }
-            s
-        },
-        Cow::Owned(s) => {
-            let (enc, _, replaced) = encoding.encode(&s);
-            if replaced { println!("warning: encountered unmappable character"); }
-            match enc {
-                Cow::Borrowed(enc) if ptr::eq(enc, s.as_bytes()) => Cow::Owned(s.into_bytes()),
-                _ => Cow::Owned(enc.into_owned())
-            }
-        }
-    }
-}
-
-fn encode_bytestring(type_: u32, inner: &[u8], buffer: &mut Vec<u8>) -> anyhow::Result<()> {
-    ensure!(inner.len() % 4 == 0, "must be divisible by 4");
-    buffer.put_u32_le(type_);
-    buffer.put_u32_le((inner.len() / 4).try_into()?);
-    buffer.put_u32_le(1);
-    buffer.put_u32_le(inner.len().try_into()?);
-    buffer.put_slice(inner);
-    Ok(())
-}
-
-fn encode_string(encoding: &'static encoding_rs::Encoding, inner: &str, buffer: &mut Vec<u8>) -> anyhow::Result<()> {
-    fn unsub_wellformed(wf: &str) -> Cow<'_, str> {
-        // note: this is a str regex
-        static PLACEHOLDER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"\\(?:x([0-9a-f]{2})|(["\\]))"#).unwrap());
-        PLACEHOLDER.replace_all(wf, |capt: &Captures<'_>| {
-            match (capt.get(1), capt.get(2)) {
-                (Some(g), None) => {
-                    // evil miniscule heap allocation LOL
-                    Cow::Owned(char::from(u8::from_str_radix(
-                        g.as_str(),
-                        16
-                    ).unwrap()).to_string())
-                },
-                (None, Some(g)) => {
-                    Cow::Borrowed(&wf[g.range()])
-                },
-                _ => unreachable!()
-            }
-        })
+// \\ is a backslash and \xNN (lowercase hex) is a byte; any other backslash is kept as is.
+// Scanning left to right means \\x41 is a backslash followed by x41, not a backslash and an A
+fn decode_label(label: &str) -> Cow<'_, [u8]> {
+    let bytes = label.as_bytes();
+    if !bytes.contains(&b'\\') {
+        return Cow::Borrowed(bytes)
     }
 
-    static MALFORMED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\\X([0-9a-f]{2})").unwrap());
-
-    let mut pieces = Vec::new();
-    let mut idx = 0;
-    while idx < inner.len() {
-        match MALFORMED.captures_at(inner, idx) {
-            None => {
-                pieces.push(cow_str_to_bytes(encoding, unsub_wellformed(&inner[idx..])));
-                break;
+    let is_hex = |b: u8| matches!(b, b'0'..=b'9' | b'a'..=b'f');
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut rest = bytes;
+    while let [first, tail @ ..] = rest {
+        match (*first, tail) {
+            (b'\\', [b'\\', tail @ ..]) => {
+                out.push(b'\\');
+                rest = tail;
             },
-            Some(malformed) => {
-                let whole = malformed.get(0).unwrap();
-                if idx != whole.start() {
-                    pieces.push(cow_str_to_bytes(encoding, unsub_wellformed(&inner[idx..whole.start()])));
-                }
-                pieces.push(Cow::Owned(vec![u8::from_str_radix(malformed.get(1).unwrap().as_str(), 16).unwrap()]));
-                idx = whole.end();
+            (b'\\', [b'x', hi, lo, tail @ ..]) if is_hex(*hi) && is_hex(*lo) => {
+                // both are ASCII hex digits, so this can't fail
+                out.push(u8::from_str_radix(str::from_utf8(&[*hi, *lo]).unwrap(), 16).unwrap());
+                rest = tail;
+            },
+            (b, tail) => {
+                out.push(b);
+                rest = tail;
             }
         }
     }
-    
-    let len = pieces.iter().map(|b| b.len()).sum::<usize>();
-
-    let nzero = 4 - len % 4;
-    let len = u32::try_from(len + nzero)?;
-    let qlen = len / 4;
-    
-    buffer.put_u32_le(0);
-    buffer.put_u32_le(qlen);
-    buffer.put_u32_le(1);
-    buffer.put_u32_le(len);
-    for piece in pieces {
-        buffer.put_slice(&piece);
-    }
-    buffer.put_bytes(0, nzero);
-    Ok(())
+    Cow::Owned(out)
 }
 
 fn split(orig: &str) -> anyhow::Result<(Vec<&str>, Option<&str>)> {
@@ -132,8 +98,11 @@ fn split(orig: &str) -> anyhow::Result<(Vec<&str>, Option<&str>)> {
         }
 
         if instr.starts_with('"') {
+            // instr is always a suffix of orig, so this maps an index in instr to a 1-based column in orig
+            let column = |idx: usize| orig[..orig.len() - instr.len() + idx].chars().count() + 1;
             let mut skip = 0u32;
             let mut end = None;
+            let mut last_escaped_quote = None;
             for (idx, ch) in instr.char_indices().skip(1) {
                 if skip > 0 {
                     skip -= 1;
@@ -145,17 +114,29 @@ fn split(orig: &str) -> anyhow::Result<(Vec<&str>, Option<&str>)> {
                     break;
                 }
 
-                if ch == '\\' && let Some(peek) = instr[idx + ch.len_utf8()..].chars().next() {
-                    if peek == '"' || peek == '\\' {
-                        skip = 1;
-                    } else if peek == 'x' {
-                        skip = 3;
-                    } else {
-                        bail!("unsupported escape: original line {orig}");
+                if ch == '\\' {
+                    let rest = &instr[idx + ch.len_utf8()..];
+                    match rest.chars().next() {
+                        Some('"') => {
+                            last_escaped_quote = Some(idx);
+                            skip = 1;
+                        },
+                        Some('\\') => skip = 1,
+                        Some(letter @ ('x' | 'X')) => {
+                            // lowercase only, like the escapes encode_string decodes
+                            ensure!(rest.len() >= 3 && rest.as_bytes()[1..3].iter().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')),
+                                "illegal escape at column {}: \\{letter} needs two lowercase hex digits: original line {orig}", column(idx));
+                            skip = 3;
+                        },
+                        Some(peek) => bail!("illegal escape \\{peek} at column {}: original line {orig}", column(idx)),
+                        None => bail!("illegal escape at column {}: backslash at the end of the line: original line {orig}", column(idx))
                     }
                 }
             }
-            let end = end.with_context(|| format!("bad quotes: original line {orig}"))?;
+            let end = end.with_context(|| match last_escaped_quote {
+                Some(idx) => format!("unclosed quote starting at column {} (the quote at column {} is escaped): original line {orig}", column(0), column(idx) + 1),
+                None => format!("unclosed quote starting at column {}: original line {orig}", column(0))
+            })?;
             parts.push(&instr[..end]);
             let tail = &instr[end..];
             instr = tail.strip_prefix(", ").unwrap_or(tail);
@@ -172,129 +153,379 @@ fn split(orig: &str) -> anyhow::Result<(Vec<&str>, Option<&str>)> {
     }
 }
 
-pub fn main(args: Args, mnemonics: BiMap<&str, u32>) -> anyhow::Result<()> {
-    static INITIAL_ADDRESS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?:[0-9A-F]{6})? +").unwrap());
-    static LABEL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^((?:[!-\[\]-~]|\\x[0-9a-f]{2})+): ").unwrap());
+// length of the `^(?:[0-9A-F]{6})? +` prefix disasm -a writes, or 0 if there is none.
+// this runs on every line, so it's matched by hand rather than with a regex
+fn initial_address_len(line: &str) -> usize {
+    let bytes = line.as_bytes();
+    let start = if bytes.len() > 6 && bytes[..6].iter().all(|b| matches!(b, b'0'..=b'9' | b'A'..=b'F')) && bytes[6] == b' ' {
+        6
+    } else {
+        0
+    };
+    let spaces = bytes[start..].iter().take_while(|&&b| b == b' ').count();
+    if spaces == 0 { 0 } else { start + spaces }
+}
+
+// Where a line came from: the file and line of an #include, or of the line itself
+struct SourceLocation {
+    file: PathBuf,
+    line: usize
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file.display(), self.line)
+    }
+}
+
+// Read path's lines, replacing each `#include "file"` (relative to the including file) with that
+// file's lines. Each line's location names every include it came through, innermost first
+fn expand_includes(path: &Path, stack: &mut Vec<SourceLocation>, lines: &mut Vec<String>, locations: &mut Vec<String>,
+    read: &mut Vec<PathBuf>) -> anyhow::Result<()>
+{
+    read.push(path.to_owned());
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    let canonical = fs::canonicalize(path)?;
+    ensure!(!stack.iter().any(|inc| fs::canonicalize(&inc.file).is_ok_and(|f| f == canonical)), "{} includes itself", path.display());
+
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        let here = SourceLocation { file: path.to_owned(), line: i + 1 };
+        if let Some(target) = line.trim_ascii().strip_prefix("#include ") {
+            let target = target.trim_ascii().strip_prefix('"').and_then(|t| t.strip_suffix('"'))
+                .with_context(|| format!("at {here}: #include needs a quoted file name"))?;
+            stack.push(here);
+            expand_includes(&path.parent().unwrap_or(Path::new("")).join(target), stack, lines, locations, read)?;
+            stack.pop();
+        } else {
+            let mut location = here.to_string();
+            for (i, inc) in stack.iter().rev().enumerate() {
+                write!(location, "{}included from {inc}", if i == 0 { " (" } else { ", " })?;
+            }
+            if !stack.is_empty() {
+                location.push(')');
+            }
+            lines.push(line);
+            locations.push(location);
+        }
+    }
+    Ok(())
+}
 
-    let mut lines = BufReader::new(File::open(args.input)?).lines().collect::<io::Result<Vec<_>>>()?;
+// Read a source file, expanding includes, dropping disasm -a addresses and joining \ continuations.
+// Also returns where each joined line starts. read collects every file opened, for --watch
+fn read_source(path: &Path, read: &mut Vec<PathBuf>) -> anyhow::Result<(Vec<String>, Vec<String>)> {
+    let (mut lines, mut locations) = (Vec::new(), Vec::new());
+    expand_includes(path, &mut Vec::new(), &mut lines, &mut locations, read)?;
+    Ok(preprocess(lines, locations))
+}
 
+fn preprocess(mut lines: Vec<String>, locations: Vec<String>) -> (Vec<String>, Vec<String>) {
     for line in &mut lines {
-        if let Cow::Owned(l) = INITIAL_ADDRESS.replace(line, "") {
-            *line = l;
+        let start = initial_address_len(line);
+        if start > 0 {
+            line.drain(..start);
         }
     }
 
-    ensure!(lines.first().is_some_and(|tag| tag.is_ascii() && tag.len() >= 7 && tag.starts_with(".tag \"") && tag.ends_with('"')),
+    // a trailing \ continues the junk base64 on the next line (see disasm --wrap-junk)
+    let mut joined: Vec<String> = Vec::with_capacity(lines.len());
+    let mut joined_locations = Vec::with_capacity(lines.len());
+    let mut continued = false;
+    for (line, location) in lines.into_iter().zip(locations) {
+        if continued && let Some(prev) = joined.last_mut() {
+            prev.pop();
+            prev.push_str(line.trim_ascii());
+        } else {
+            joined.push(line);
+            joined_locations.push(location);
+        }
+        continued = joined.last().is_some_and(|l| l.contains(" ! ") && l.ends_with('\\'));
+    }
+    (joined, joined_locations)
+}
+
+// Everything from source lines to the file's structure, without touching the output
+fn build(args: &Args, mnemonics: &BiMap<&str, u32>, confs: &[Yaml<'static>], mut lines: Vec<String>, mut locations: Vec<String>,
+    read: &mut Vec<PathBuf>, lap: &mut dyn FnMut(&str)) -> anyhow::Result<(Stcm2, u32)>
+{
+    let allowed_opcodes = super::allowed_opcodes(confs)?;
+    let signatures = super::opcode_signatures(mnemonics, confs)?;
+    // any printable ASCII except backslash is allowed anywhere in a label (digits included,
+    // so `1stScene` and `fn_00001` are both fine); other bytes must be written as \xNN, and a backslash may also be \\
+    static LABEL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^((?:[!-\[\]-~]|\\x[0-9a-f]{2}|\\\\)+): ").unwrap());
+
+    // old opcode -> new opcode, matched up by mnemonic name
+    let remap = if let Some(ref paths) = args.remap_opcodes {
+        let (old, new) = (super::load_config(&paths[0])?, super::load_config(&paths[1])?);
+        let new = super::parse_mnemonics(&new)?;
+        super::parse_mnemonics(&old)?.iter()
+            .filter_map(|(name, &old)| Some((old, *new.get_by_left(name)?)))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    // disasm records a non-default encoding in a comment before the tag
+    let comment_encoding = match lines.first().and_then(|l| l.strip_prefix("; [encoding: ")?.strip_suffix(']')) {
+        Some(name) => {
+            let encoding = super::Encoding::from_str(name, false).map_err(|e| anyhow!("bad encoding comment: {e}"))?;
+            lines.remove(0);
+            locations.remove(0);
+            Some(encoding)
+        },
+        None => None
+    };
+    let encoding = match (args.encoding, comment_encoding) {
+        (Some(flag), Some(comment)) if flag != comment => {
+            super::warn(format_args!("file says it is {comment:?}, but assembling as {flag:?} because of -e"))?;
+            flag
+        },
+        (flag, comment) => flag.or(comment).unwrap_or_default()
+    }.get();
+
+    // .tag "..." may be followed by the variant when the tag doesn't imply it
+    let (tag_line, tag_variant) = match lines.first().and_then(|l| l.rsplit_once("\" ")) {
+        Some((head, variant)) if !variant.contains('"') =>
+            (format!("{head}\""), Some(Variant::from_str(variant, false).map_err(|e| anyhow!("bad variant after tag: {e}"))?)),
+        _ => (lines.first().cloned().unwrap_or_default(), None)
+    };
+    ensure!(tag_line.is_ascii() && tag_line.len() >= 7 && tag_line.starts_with(".tag \"") && tag_line.ends_with('"'),
         "improper tag");
     
-    let tag = Bytes::from(mem::take(&mut lines[0]).into_bytes());
+    let tag = Bytes::from(tag_line.into_bytes());
     let tag = tag.slice(6..tag.len()-1);
 
-    let filler = if tag.starts_with(b"L") { 0x40000000 } else { 0xff000000 };
-    println!("using filler 0x{filler:08x}");
+    let variant = tag_variant.unwrap_or_else(|| Variant::from_tag(&tag));
+    let filler = match args.output_format {
+        _ if let Some(filler) = args.force_filler => {
+            if filler != variant.filler() {
+                super::warn(format_args!("tag {:?} looks like {variant:?}, but forcing filler 0x{filler:08x}", BStr::new(&tag)))?;
+            }
+            filler
+        },
+        Some(format) => {
+            if format != variant {
+                super::warn(format_args!("tag {:?} looks like {variant:?}, but writing {format:?}", BStr::new(&tag)))?;
+            }
+            format.filler()
+        },
+        None => variant.filler()
+    };
 
     ensure!(lines.get(1).is_some_and(|gd| gd.is_ascii() && gd.starts_with(".global_data ")),
         "improper global data");
     
     let global_data = Bytes::from(BASE64_STANDARD_NO_PAD.decode(&lines[1][13..])?);
+    // from_bytes scans for CODE_START_ a word at a time, so anything else would shift every global_data pointer
+    ensure!(global_data.len().is_multiple_of(4),
+        "global_data length {} is not 4-byte aligned; file cannot be correctly assembled", global_data.len());
+
+    // .unk_header is optional, since it's almost always zero
+    let (unk_header, code_start) = match lines.get(2).and_then(|l| l.strip_prefix(".unk_header ")) {
+        Some(b64) => {
+            let unk_header = BASE64_STANDARD_NO_PAD.decode(b64).context("bad .unk_header")?;
+            ensure!(unk_header.len() == stcm2::UNK_HEADER_LENGTH,
+                ".unk_header is {} bytes (must be {})", unk_header.len(), stcm2::UNK_HEADER_LENGTH);
+            (Bytes::from(unk_header), 3)
+        },
+        None => (Bytes::from_static(&[0; stcm2::UNK_HEADER_LENGTH]), 2)
+    };
+
+    // .export_name_length comes from disasm, for files whose export name field isn't the usual 32 bytes
+    let (max_export, code_start) = match lines.get(code_start).and_then(|l| l.strip_prefix(".export_name_length ")) {
+        Some(n) => {
+            let n = n.parse::<usize>().context("bad .export_name_length")?;
+            ensure!(n > 0, ".export_name_length {n} is not positive");
+            (n, code_start + 1)
+        },
+        None => (super::export_name_length(confs)?.unwrap_or(stcm2::EXPORT_NAME_LENGTH), code_start)
+    };
+
+    ensure!(lines.get(code_start).map(|s| &s[..]) == Some(".code_start"), "improper code start");
 
-    ensure!(lines.get(2).map(|s| &s[..]) == Some(".code_start"), "improper code start");
+    // --concat files contribute everything after their own .code_start; labels resolve across all of them
+    let mut code = lines.into_iter().zip(locations).skip(code_start + 1).collect::<Vec<_>>();
+    for path in &args.concat {
+        let (lines, locations) = read_source(path, read)?;
+        let start = lines.iter().position(|l| l == ".code_start").with_context(|| format!("no .code_start in {}", path.display()))?;
+        code.extend(lines.into_iter().zip(locations).skip(start + 1));
+    }
 
-    let code = &lines[3..];
+    lap("line preprocessing");
 
-    let mut actions = Vec::new();
+    let mut actions: Vec<Action> = Vec::new();
 
     // a table of references that are yet to be resolved
     // the index is used to calculate the sentinel value which is used for global calls and pointers
     let mut pending_references = IndexMap::new();
 
-    for instr in code {
-        if instr.is_empty() { continue }
+    let code_base = stcm2::GLOBAL_DATA_OFFSET + global_data.len() + stcm2::CODE_START_MAGIC.len();
+    let mut errors = Vec::new();
 
-        let count = u32::try_from(actions.len())?;
+    for (line, location) in &code {
+        if line.is_empty() { continue }
 
-        let mut instr = &instr[..];
+        let result = (|| -> anyhow::Result<()> {
+            let count = u32::try_from(actions.len())?;
 
-        let mut label = LABEL.captures(instr).map(|label| {
-            instr = instr.strip_prefix(label.get(0).unwrap().as_str()).unwrap();
-            label.get(1).unwrap().as_str()
-        }).map(decode_label);
+            // disasm --encoding-fallback marks lines whose strings aren't in the main encoding
+            let (mut instr, candidates) = match line.rsplit_once(" ; encoding: ") {
+                Some((head, name)) if let Ok(line_encoding) = super::Encoding::from_str(name, false) => (head, vec![line_encoding.get()]),
+                _ => (&line[..], iter::once(encoding).chain(args.encoding_fallback.iter().map(|e| e.get())).collect::<Vec<_>>())
+            };
 
-        if let Some(lbl) = label.clone() {
-            if lbl.starts_with(b"local_") || lbl.starts_with(b"fn_") {
-                label = None;
+            if let Some(n) = instr.strip_prefix(".padding ") {
+                let n = n.parse::<usize>().with_context(|| format!("bad padding: {instr}"))?;
+                ensure!(n.is_multiple_of(4), "padding must be a multiple of 4: {instr}");
+                actions.last_mut().context(".padding before the first action")?.padding += n;
+                return Ok(())
             }
-            pending_references.insert(lbl, Some(count));
-        }
 
-        let (split, junk) = split(instr)?;
-        let op = split[0];
-        let junk = junk.unwrap_or_default();
-
-        let (call, opcode) = if let Some(op) = op.strip_prefix("raw ") {
-            let opcode = u32::from_str_radix(op, 16)?;
-            (false, opcode)
-        } else if let Some(&opcode) = mnemonics.get_by_left(op) {
-            (false, opcode)
-        } else if let Some(op) = op.strip_prefix("call ") {
-            let op = decode_label(op);
-            let ent = pending_references.entry(op);
-            let idx = ent.index();
-            ent.or_default();
-            let opcode = !u32::try_from(idx)?;
-            (true, opcode)
-        } else {
-            bail!("invalid op {op}");
-        };
-
-        let mut data = Vec::new();
-        BASE64_STANDARD_NO_PAD.decode_vec(junk, &mut data)?;
-
-        let params = split[1..].iter().map(|&param| Ok(
-            if let Some(s) = param.strip_prefix('"') {
-                let s = s.strip_suffix('"').with_context(|| format!("no ending quote for {instr}"))?;
-                let ptr = u32::try_from(data.len())?;
-                encode_string(args.encoding.get(), s, &mut data)?;
-                Parameter::DataPointer(ptr)
-            } else if let Some(lit) = param.strip_prefix(['=', '@']) {
-                let (type_, lit) = if let Some(lit) = lit.strip_prefix('=') {
-                    (1, lit)
-                } else {
-                    (0, lit)
-                };
-                let lit = if let Some(lit) = lit.strip_suffix('h') {
-                    u32::from_str_radix(lit, 16)?
-                } else {
-                    lit.parse()?
-                };
-                let ptr = u32::try_from(data.len())?;
-                encode_bytestring(type_, &lit.to_le_bytes(), &mut data)?;
-                Parameter::DataPointer(ptr)
-            } else if let Some(param) = param.strip_prefix('[') {
-                let param = param.strip_suffix(']').context("no matching bracket??")?;
-                if let Some(ptr) = param.strip_prefix("global_data+") {
-                    Parameter::GlobalDataPointer(ptr.parse()?)
+            if let Some(n) = instr.strip_prefix(".align ") {
+                let n = n.parse::<usize>().with_context(|| format!("bad alignment: {instr}"))?;
+                ensure!(n.is_power_of_two() && n >= 4, "alignment must be a power of two of at least 4: {instr}");
+                // same layout as to_bytes: code starts right after the global data
+                let end = code_base + actions.iter().map(|act| act.len() + act.padding).sum::<usize>();
+                let pad = end.next_multiple_of(n) - end;
+                actions.last_mut().context(".align before the first action")?.padding += pad;
+                return Ok(())
+            }
+
+            let mut label = LABEL.captures(instr).map(|label| {
+                instr = instr.strip_prefix(label.get(0).unwrap().as_str()).unwrap();
+                label.get(1).unwrap().as_str()
+            }).map(decode_label);
+
+            if let Some(lbl) = label.clone() {
+                // disasm's autolabels aren't exports; they still resolve calls and refs made before them
+                if lbl.starts_with(b"local_") || lbl.starts_with(b"fn_") {
+                    label = None;
+                } else if args.null_terminate_exports {
+                    // drop anything after an embedded null and leave room for the terminator
+                    let name = lbl.split(|&b| b == 0).next().unwrap_or_default();
+                    ensure!(name.len() < max_export, "export label {} is {} bytes long (max {} with a null terminator)", BStr::new(name), name.len(), max_export - 1);
+                    label = Some(Cow::Owned(name.to_vec()));
                 } else {
-                    let ent = pending_references.entry(decode_label(param));
-                    let idx = ent.index();
-                    ent.or_default();
-                    let ptr = !u32::try_from(idx)?;
-                    Parameter::ActionRef(ptr)
+                    ensure!(lbl.len() <= max_export, "export label {} is {} bytes long (max {max_export})", BStr::new(&lbl), lbl.len());
                 }
+                pending_references.insert(lbl, Some(count));
+            }
+
+            let (split, junk) = split(instr)?;
+            let op = split[0];
+            let junk = junk.unwrap_or_default();
+
+            let (call, opcode) = if let Some(op) = op.strip_prefix("raw ") {
+                // mnemonics are already looked up in the new table; only raw numbers are old
+                let opcode = u32::from_str_radix(op, 16)?;
+                (false, remap.get(&opcode).copied().unwrap_or(opcode))
+            } else if let Some(&opcode) = mnemonics.get_by_left(op) {
+                (false, opcode)
+            } else if let Some(op) = op.strip_prefix("call ") {
+                let op = decode_label(op);
+                let ent = pending_references.entry(op);
+                let idx = ent.index();
+                ent.or_default();
+                let opcode = !u32::try_from(idx)?;
+                (true, opcode)
             } else {
-                Parameter::Value(u32::from_str_radix(param, 16)?)
+                bail!("invalid op {op}");
+            };
+            if !call && let Some(ref allowed) = allowed_opcodes {
+                ensure!(allowed.iter().any(|r| r.contains(&opcode)), "opcode {opcode:X} is outside config.yaml's allowed_opcodes");
             }
-        )).collect::<anyhow::Result<Vec<Parameter>>>()?;
-
-        actions.push(Action {
-            export: label.map(|s| Bytes::from(s.into_owned())),
-            call,
-            opcode,
-            params,
-            data: data.into()
-        });
+
+            let mut data = Vec::new();
+            BASE64_STANDARD_NO_PAD.decode_vec(junk, &mut data)?;
+            // strings are word-aligned, so misaligned junk would only show up later as a confusing encoding error
+            ensure!(data.len().is_multiple_of(4),
+                "junk data is not 4-byte aligned after decoding ({} bytes)", data.len());
+            if args.strip_junk {
+                // nothing but junk has been written yet
+                data.fill(0);
+            }
+
+            // one encoding for every string in the action, since disasm marks at most one per line
+            let strings = split[1..].iter().filter_map(|param| param.strip_prefix('"')?.strip_suffix('"')).collect::<Vec<_>>();
+            let line_encoding = candidates.iter().copied()
+                .find(|&encoding| strings.iter().all(|s| stcm2::can_encode(encoding, s)))
+                .unwrap_or(candidates[0]);
+
+            let params = split[1..].iter().map(|&param| Ok(
+                if let Some(s) = param.strip_prefix('"') {
+                    let s = s.strip_suffix('"').with_context(|| format!("no ending quote for {instr}"))?;
+                    let ptr = u32::try_from(data.len())?;
+                    encode_string(line_encoding, s, &mut data)?;
+                    Parameter::DataPointer(ptr)
+                } else if let Some((type_, lit)) = param.strip_prefix("@=").map(|lit| (1, lit))
+                    .or_else(|| param.strip_prefix(['=', '@']).map(|lit| (0, lit)))
+                {
+                    // =N is a type 0 integer and @=N a type 1 integer, decimal or hex with an h suffix.
+                    // Older disassemblies wrote type 0 as @N, so that still means the same as =N
+                    let lit = if let Some(lit) = lit.strip_suffix('h') {
+                        u32::from_str_radix(lit, 16)?
+                    } else {
+                        lit.parse()?
+                    };
+                    let ptr = u32::try_from(data.len())?;
+                    encode_bytestring(type_, &lit.to_le_bytes(), &mut data)?;
+                    Parameter::DataPointer(ptr)
+                } else if let Some(param) = param.strip_prefix('[').map(|p| p.strip_suffix(']').context("no matching bracket??"))
+                    .or_else(|| param.strip_prefix("call ").map(Ok))
+                {
+                    // `call label` is how disasm --semantic-calls writes a function pointer; it's the same as [label]
+                    let param = param?;
+                    if let Some(ptr) = param.strip_prefix("global_data+") {
+                        Parameter::GlobalDataPointer(ptr.parse()?)
+                    } else {
+                        let ent = pending_references.entry(decode_label(param));
+                        let idx = ent.index();
+                        ent.or_default();
+                        let ptr = !u32::try_from(idx)?;
+                        Parameter::ActionRef(ptr)
+                    }
+                } else if param.starts_with('-') {
+                    // negative decimal, as printed by disasm --signed
+                    Parameter::Value(param.parse::<i32>()? as u32)
+                } else {
+                    Parameter::Value(u32::from_str_radix(param, 16)?)
+                }
+            )).collect::<anyhow::Result<Vec<Parameter>>>()?;
+
+            if !call && let Some(&nparams) = signatures.get(&opcode) {
+                ensure!(params.len() == nparams, "{op} takes {nparams} params, but was given {}", params.len());
+            }
+
+            actions.push(Action {
+                export: label.map(|s| Bytes::from(s.into_owned())),
+                call,
+                opcode,
+                params,
+                data: data.into(),
+                padding: 0
+            });
+            Ok(())
+        })();
+
+        if let Err(e) = result {
+            errors.push(e.context(format!("at {location}: {line}")));
+            if errors.len() >= args.max_errors.max(1) { break }
+        }
     }
 
+    // a single error is returned as-is; otherwise report everything collected
+    if errors.len() == 1 {
+        return Err(errors.pop().unwrap());
+    } else if !errors.is_empty() {
+        for e in &errors {
+            eprintln!("error: {e:#}");
+        }
+        bail!("assembly failed with {} errors", errors.len());
+    }
+
+    lap("parameter encoding");
+
     // resolve all pending references in the Vec context
     for action in &mut actions {
         if action.call {
@@ -310,121 +541,273 @@ pub fn main(args: Args, mnemonics: BiMap<&str, u32>) -> anyhow::Result<()> {
         }
     }
 
-    // temporary table to handle renaming pointers
-    let mut counter = 0;
-    let renames = actions.iter().map(|act| {
-        let pos = u32::try_from(counter)?;
-        counter += act.len();
-        Ok(pos)
-    }).collect::<anyhow::Result<Vec<_>>>()?;
-
-    // rename pointers relative to code_start
-    let actions = actions.into_iter().enumerate().map(|(i, mut act)| {
-        let renamed_i = renames[i];
-        if act.call {
-            act.opcode = renames[usize::try_from(act.opcode)?];
+    lap("label resolution");
+
+    // actions are keyed by their index; to_bytes lays them out in that order
+    let stcm2 = Stcm2 {
+        tag,
+        variant,
+        recorded_file_len: None,
+        unk_header,
+        global_data,
+        export_name_length: max_export,
+        actions: (0..).zip(actions).collect()
+    };
+    Ok((stcm2, filler))
+}
+
+// read collects the source files it got to, even if assembling them failed, for --watch
+fn assemble(args: &Args, mnemonics: &BiMap<&str, u32>, confs: &[Yaml<'static>], read: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    // --timing: report how long each phase took
+    let mut last = Instant::now();
+    let mut lap = |phase: &str| {
+        if args.timing {
+            eprintln!("{phase}: {:.2?}", last.elapsed());
         }
-        for param in &mut act.params {
-            if let Parameter::ActionRef(ptr) = param {
-                *ptr = renames[usize::try_from(*ptr)?];
-            }
+        last = Instant::now();
+    };
+
+    let (lines, locations) = read_source(&args.input, read)?;
+    lap("file read");
+
+    let (stcm2, filler) = build(args, mnemonics, confs, lines, locations, read, &mut lap)?;
+    println!("using filler 0x{filler:08x}");
+
+    let out = stcm2::to_bytes(&stcm2, filler)?;
+    lap("serialization");
+
+    if args.emit_sizes {
+        let header = stcm2::GLOBAL_DATA_OFFSET;
+        let global = stcm2.global_data.len();
+        let code = stcm2::CODE_START_MAGIC.len() + stcm2.code_size();
+        let nexports = stcm2.actions.values().filter(|act| act.export.is_some()).count();
+        let exports = stcm2::EXPORT_DATA_MAGIC.len() + nexports * (8 + stcm2.export_name_length);
+        let collection = out.len() - header - global - code - exports;
+        println!("Header: {} bytes | Global Data: {} bytes | Code: {} bytes ({} actions) | Export Data: {} bytes ({nexports} exports) | Collection Link: {} bytes | Total: {} bytes",
+            thousands(header), thousands(global), thousands(code), stcm2.action_count(), thousands(exports), thousands(collection), thousands(out.len()));
+    }
+
+    // the tag only suggests a filler, so a mismatch here is the usual reason a round trip fails
+    if let Some(ref path) = args.verify_roundtrip {
+        let original = fs::read(path)?;
+        let parsed = stcm2::from_bytes(original.clone().into()).with_context(|| format!("failed to parse {}", path.display()))?;
+        if let Some(original_filler) = stcm2::param_filler(&parsed, &original) && original_filler != filler {
+            super::warn(format_args!("{} uses filler 0x{original_filler:08x}, but assembled with 0x{filler:08x} (try --force-filler {original_filler:08x})", path.display()))?;
+        }
+        if let Some(first) = original.iter().zip(&out).position(|(a, b)| a != b).or((original.len() != out.len()).then(|| original.len().min(out.len()))) {
+            bail!("output differs from {} starting at 0x{first:X}", path.display());
         }
+        lap("round trip verification");
+    }
 
-        Ok((renamed_i, act))
-    }).collect::<anyhow::Result<Vec<_>>>()?;
+    // skip identical writes so incremental builds don't see a modification
+    let existing = fs::read(&args.output).ok();
+    if let Some(existing) = existing {
+        if existing == out {
+            println!("{} is unchanged", args.output.display());
+            lap("binary write");
+            return Ok(())
+        }
 
-    let mut out = Vec::new();
+        let first = existing.iter().zip(&out).position(|(a, b)| a != b).unwrap_or(existing.len().min(out.len()));
+        let changed = existing.iter().zip(&out).filter(|(a, b)| a != b).count() + existing.len().abs_diff(out.len());
+        println!("{} differs: first difference at 0x{first:X}, {changed} bytes changed", args.output.display());
+    }
 
-    out.put_slice(STCM2_MAGIC);
-    out.put_slice(&tag);
-    out.put_bytes(0, STCM2_TAG_LENGTH - tag.len());
-    let meta_idx = out.len();
-    out.put_bytes(0, 4*12); // todo: this is incorrect (figure out unk values)
-    out.put_slice(GLOBAL_DATA_MAGIC);
-    ensure!(out.len() == GLOBAL_DATA_OFFSET);
-    out.put_slice(&global_data);
-    out.put_slice(CODE_START_MAGIC);
+    ensure!(!args.check, "{} would change", args.output.display());
 
-    let mut exports = Vec::new();
+    fs::write(&args.output, out)?;
+    lap("binary write");
 
-    let code_base = out.len();
-    for (pos, mut act) in actions {
-        ensure!(out.len() == code_base + usize::try_from(pos)?);
+    Ok(())
+}
 
-        if let Some(export) = act.export.take() {
-            exports.push((export, out.len()));
+// 15928 -> 15,928
+fn thousands(n: usize) -> String {
+    let digits = n.to_string();
+    let mut out = String::new();
+    for (i, ch) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
         }
+        out.push(ch);
+    }
+    out
+}
 
-        out.put_u32_le(act.call.into());
-        out.put_u32_le(if act.call {
-            u32::try_from(code_base + usize::try_from(act.opcode)?)?
-        } else {
-            act.opcode
-        });
-        out.put_u32_le(u32::try_from(act.params.len())?);
-        out.put_u32_le(u32::try_from(act.len())?);
-
-        let data_base = out.len() + 12 * act.params.len();
-        for param in act.params {
-            match param {
-                Parameter::Value(val) => {
-                    out.put_u32_le(val);
-                    out.put_u32_le(filler);
-                    out.put_u32_le(filler);
-                },
-                Parameter::GlobalDataPointer(ptr) => {
-                    out.put_u32_le(u32::try_from(GLOBAL_DATA_OFFSET)? + ptr);
-                    out.put_u32_le(filler);
-                    out.put_u32_le(filler);
-                },
-                Parameter::DataPointer(ptr) => {
-                    out.put_u32_le(u32::try_from(data_base + usize::try_from(ptr)?)?);
-                    out.put_u32_le(filler);
-                    out.put_u32_le(filler);
-                },
-                Parameter::ActionRef(ptr) => {
-                    out.put_u32_le(0xffffff41);
-                    out.put_u32_le(u32::try_from(code_base + usize::try_from(ptr)?)?);
-                    out.put_u32_le(filler);
-                }
+// HH:MM:SS (UTC) for watch mode's rebuild messages
+fn timestamp() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    format!("{:02}:{:02}:{:02}", secs / 3600 % 24, secs / 60 % 60, secs % 60)
+}
+
+// Assemble source text in memory, with asm flags as they'd be given on the command line
+pub fn build_text(flags: &[&str], text: &str, mnemonics: &BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<(Stcm2, u32)> {
+    // from_global args only exist when parsed as a subcommand
+    let argv = ["stcm2-asm", "asm"].into_iter().chain(flags.iter().copied()).chain(["<disassembly>", "<none>"]);
+    let super::Args { cmd: super::Command::Asm(args), .. } = super::Args::try_parse_from(argv)? else {
+        unreachable!()
+    };
+    let (lines, locations) = text.lines().enumerate().map(|(i, l)| (l.to_owned(), format!("line {}", i + 1))).unzip();
+    let (lines, locations) = preprocess(lines, locations);
+    build(&args, mnemonics, confs, lines, locations, &mut Vec::new(), &mut |_| ())
+}
+
+// Check that text would assemble with default options, as disasm --check-format does
+pub fn check_source(text: &str, mnemonics: &BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    build_text(&[], text, mnemonics, confs)?;
+    Ok(())
+}
+
+pub fn main(args: Args, mnemonics: BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    if !args.watch {
+        return assemble(&args, &mnemonics, confs, &mut Vec::new())
+    }
+
+    // editors often write a file more than once per save, so wait until things have been quiet
+    // for a moment before rebuilding
+    const DEBOUNCE: Duration = Duration::from_millis(100);
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    loop {
+        let mut read = Vec::new();
+        match assemble(&args, &mnemonics, confs, &mut read) {
+            Ok(()) => println!("[{}] assembled {}", timestamp(), args.output.display()),
+            Err(e) => eprintln!("[{}] error: {e:#}", timestamp())
+        }
+
+        // watch the directories rather than the files themselves, since many editors save by replacing
+        // the file; the input is watched even if it couldn't be read, so creating it triggers a build
+        let mut watched = BTreeSet::new();
+        for path in read.iter().chain([&args.input]) {
+            let dir = path.parent().filter(|d| !d.as_os_str().is_empty()).unwrap_or(Path::new("."));
+            if let (Ok(dir), Some(name)) = (fs::canonicalize(dir), path.file_name()) {
+                watched.insert(dir.join(name));
             }
         }
+        let dirs = watched.iter().filter_map(|path| path.parent()).collect::<BTreeSet<_>>();
+        for &dir in &dirs {
+            watcher.watch(dir, RecursiveMode::NonRecursive)?;
+        }
+
+        let relevant = |event: notify::Result<notify::Event>| event.is_ok_and(|event|
+            !event.kind.is_access() && event.paths.iter().any(|path| watched.contains(path)));
+        while !relevant(rx.recv()?) {}
+        while rx.recv_timeout(DEBOUNCE).is_ok() {}
 
-        out.put_slice(&act.data);
+        for dir in dirs {
+            watcher.unwatch(dir)?;
+        }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use crate::stcm2::StringType;
 
-    out.put_slice(EXPORT_DATA_MAGIC);
-    let export_addr = out.len();
-    {
-        let mut export_meta = &mut out[meta_idx..];
-        export_meta.put_u32_le(u32::try_from(export_addr)?);
-        export_meta.put_u32_le(u32::try_from(exports.len())?);
+    use super::*;
+
+    const HEADER: &str = ".tag \"TEST\"\n.global_data AAAAAAAAAAA\n.code_start\n";
+
+    fn opcodes(stcm2: &Stcm2) -> Vec<u32> {
+        stcm2.actions.values().map(|act| act.opcode).collect()
     }
-    for (name, addr) in exports {
-        out.put_u32_le(0);
-        out.put_slice(&name);
-        out.put_bytes(0, 32 - name.len());
-        out.put_u32_le(u32::try_from(addr)?);
+
+    #[test]
+    fn remap_only_renumbers_raw_opcodes() {
+        let dir = env::temp_dir().join(format!("stcm2-asm-remap-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (old, new) = (dir.join("old.yaml"), dir.join("new.yaml"));
+        fs::write(&old, "mnemonics:\n  foo: 1\n  bar: 2\n").unwrap();
+        fs::write(&new, "mnemonics:\n  foo: 2\n  bar: 3\n").unwrap();
+
+        let mnemonics = BiMap::from_iter([("foo", 2), ("bar", 3)]);
+        let source = format!("{HEADER}foo\nraw 1\nraw 2\n");
+        let flags = ["--remap-opcodes", old.to_str().unwrap(), new.to_str().unwrap()];
+        let (stcm2, _) = build_text(&flags, &source, &mnemonics, &[]).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(opcodes(&stcm2), [2, 2, 3]);
     }
-    
-    out.put_slice(COLLECTION_LINK_MAGIC);
-    let collection_link_len = 2;
-    let collection_link_addr = out.len();
-    {
-        let mut collection_meta = &mut out[meta_idx+8..];
-        collection_meta.put_u32_le(collection_link_len);
-        collection_meta.put_u32_le(collection_link_addr.try_into()?);
+
+    #[test]
+    fn split_skips_hex_escapes() {
+        let (parts, junk) = split(r#"raw 10, "\X2c, \x2c", 5 ! AAAA"#).unwrap();
+        assert_eq!(parts, ["raw 10", r#""\X2c, \x2c""#, "5"]);
+        assert_eq!(junk, Some("AAAA"));
+
+        let (stcm2, _) = build_text(&[], &format!("{HEADER}raw 10, \"abcdef\\X82\\x41\"\n"), &BiMap::new(), &[]).unwrap();
+        let act = stcm2.actions.values().next().unwrap();
+        assert_eq!(act.data_strings(encoding_rs::UTF_8).unwrap()[0].1, StringType::String(Bytes::from_static(b"abcdef\x82A")));
+
+        assert!(split(r#"raw 10, "\xAB""#).unwrap_err().to_string().contains(r"\x needs two lowercase hex digits"));
+        assert!(split(r#"raw 10, "\X8""#).unwrap_err().to_string().contains(r"\X needs two lowercase hex digits"));
+    }
+
+    #[test]
+    fn fallback_encoding_is_chosen_per_action() {
+        // Shift-JIS has no é, so the whole action falls back to UTF-8, not just the first string
+        let source = format!("{HEADER}raw 10, \"café\", \"こんにちは\"\n");
+        let (stcm2, _) = build_text(&["-e", "sjis", "--encoding-fallback", "utf-8"], &source, &BiMap::new(), &[]).unwrap();
+        let act = stcm2.actions.values().next().unwrap();
+        let strings = act.data_strings(encoding_rs::UTF_8).unwrap().into_iter().map(|(_, s)| s).collect::<Vec<_>>();
+        assert_eq!(strings, [StringType::String(Bytes::from("café")), StringType::String(Bytes::from("こんにちは"))]);
     }
-    out.put_u32_le(0);
-    let write_file_len_here = out.len();
-    out.put_bytes(0, 60);
-    {
-        let len = out.len();
-        let mut write_file_len = &mut out[write_file_len_here..];
-        write_file_len.put_u32_le(len.try_into()?);
+
+    #[test]
+    fn label_escapes_decode_left_to_right() {
+        assert_eq!(&*decode_label(r"\x41"), b"A");
+        assert_eq!(&*decode_label(r"\\x41"), br"\x41");
+        assert_eq!(&*decode_label(r"\\\x41"), br"\A");
+        assert_eq!(&*decode_label(r"a\x82\\b"), b"a\x82\\b");
+        assert_eq!(&*decode_label("plain"), b"plain");
     }
 
-    fs::write(args.output, out)?;
+    // assemble code and read the written file back, so references have their final addresses
+    fn assemble_and_parse(code: &str) -> Stcm2 {
+        let mnemonics = BiMap::from_iter([("return", 0)]);
+        let (stcm2, filler) = build_text(&[], &format!("{HEADER}{code}\n"), &mnemonics, &[]).unwrap();
+        stcm2::from_bytes(stcm2::to_bytes(&stcm2, filler).unwrap().into()).unwrap()
+    }
 
-    Ok(())
-}
\ No newline at end of file
+    #[test]
+    fn calls_resolve_fn_autolabels_defined_later() {
+        let stcm2 = assemble_and_parse("main: call fn_A, [local_B]\nreturn\nfn_A: raw 5\nlocal_B: return");
+        let (&main, _) = stcm2.actions.iter().next().unwrap();
+        let fn_a = stcm2.actions.iter().find(|(_, act)| act.opcode == 5).map(|(&addr, _)| addr).unwrap();
+        let local_b = *stcm2.actions.keys().last().unwrap();
+
+        let call = &stcm2.actions[&main];
+        assert!(call.call);
+        assert_eq!(call.opcode, fn_a);
+        assert_eq!(call.params, [Parameter::ActionRef(local_b)]);
+        // autolabels only name actions in the source; they aren't exported
+        assert_eq!(stcm2.actions.values().filter_map(Action::label).collect::<Vec<_>>(), [b"main"]);
+    }
+
+    #[test]
+    fn labels_may_start_with_digits_or_underscores() {
+        let stcm2 = assemble_and_parse("1stScene: call fn_00001\nscene_1: return\n_start: raw 1, [1stScene]\nfn_00001: return");
+        let labels = stcm2.actions.values().filter_map(Action::label).collect::<Vec<_>>();
+        assert_eq!(labels, [&b"1stScene"[..], b"scene_1", b"_start"]);
+        let first = *stcm2.actions.keys().next().unwrap();
+        assert_eq!(stcm2.actions.values().nth(2).unwrap().params, [Parameter::ActionRef(first)]);
+    }
+
+    // the integer operands of the first action, read back from the written file
+    fn int_operands(source: &str) -> Vec<StringType> {
+        let stcm2 = assemble_and_parse(source);
+        let act = stcm2.actions.values().next().unwrap();
+        act.data_strings(encoding_rs::UTF_8).unwrap().into_iter().map(|(_, s)| s).collect()
+    }
+
+    #[test]
+    fn int_operands_round_trip() {
+        assert_eq!(int_operands("raw 10, =5, @=5"), [StringType::Type0U32(5), StringType::Type1U32(5)]);
+        assert_eq!(int_operands("raw 10, =FFh, @=DEADBEEFh"), [StringType::Type0U32(0xFF), StringType::Type1U32(0xDEADBEEF)]);
+        assert_eq!(int_operands("raw 10, @5, @1Ah"), [StringType::Type0U32(5), StringType::Type0U32(0x1A)]);
+    }
+}
@@ -0,0 +1,44 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{ensure, Context as _};
+use clap::Parser;
+
+use crate::stcm2::{self, hex_bytes, Parameter, StringType};
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(long, value_enum, help = "encoding every string should decode under")]
+    expected: super::Encoding,
+    file: PathBuf
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let stcm2 = stcm2::from_bytes(fs::read(&args.file)?.into()).with_context(|| format!("failed to parse {}", args.file.display()))?;
+    let encoding = args.expected.get();
+
+    let mut total = 0;
+    let mut failures = 0;
+    for (&addr, act) in &stcm2.actions {
+        let strings = act.data_strings(encoding).with_context(|| format!("action at {addr:06X}"))?;
+        for (pos, s) in strings {
+            let StringType::String(s) = s else { continue };
+            total += 1;
+            if encoding.decode_without_bom_handling_and_without_replacement(&s).is_some() {
+                continue
+            }
+
+            failures += 1;
+            let params = act.params.iter().enumerate()
+                .filter(|&(_, &p)| p == Parameter::DataPointer(pos))
+                .map(|(i, _)| i.to_string())
+                .collect::<Vec<_>>();
+            let params = if params.is_empty() { "no param".to_owned() } else { format!("param {}", params.join(", ")) };
+            println!("action at {addr:06X}, {params} (data+{pos:X}): {}", hex_bytes(&s));
+        }
+    }
+
+    println!("{failures} of {total} strings are not valid {}", encoding.name());
+    ensure!(failures == 0, "{} does not look like {}", args.file.display(), encoding.name());
+
+    Ok(())
+}
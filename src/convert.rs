@@ -0,0 +1,78 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use anyhow::Context as _;
+use bstr::BStr;
+use clap::Parser;
+
+use crate::stcm2::{self, encode_raw_string, Parameter, Stcm2, StringType};
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(long, value_enum, help = "text encoding of the input file")]
+    from: super::Encoding,
+    #[arg(long, value_enum, help = "text encoding of the output file")]
+    to: super::Encoding,
+    input: PathBuf,
+    output: PathBuf
+}
+
+// Rewrite every string in every action's data, fixing up the data pointers that refer to them.
+// f gets the action address, the string's offset and its raw bytes; None keeps it as it is
+pub fn map_strings(stcm2: &mut Stcm2, from: &'static encoding_rs::Encoding, mut f: impl FnMut(u32, u32, &[u8]) -> anyhow::Result<Option<Vec<u8>>>) -> anyhow::Result<()> {
+    for (&addr, act) in &mut stcm2.actions {
+        let strings = act.data_strings(from).with_context(|| format!("action at {addr:06X}"))?;
+        let junk = strings.first().map_or(&act.data[..], |&(pos, _)| &act.data[..pos as usize]);
+
+        // junk only ever appears at the beginning, so it keeps its offset
+        let mut data = junk.to_vec();
+        let mut moved = HashMap::new();
+
+        for (i, &(pos, ref s)) in strings.iter().enumerate() {
+            let end = strings.get(i + 1).map_or(act.data.len(), |&(next, _)| next as usize);
+            moved.insert(pos, u32::try_from(data.len())?);
+
+            let StringType::String(ref s) = *s else {
+                data.extend_from_slice(&act.data[pos as usize..end]);
+                continue
+            };
+
+            match f(addr, pos, s)? {
+                Some(encoded) => encode_raw_string(&encoded, &mut data)?,
+                None => data.extend_from_slice(&act.data[pos as usize..end])
+            }
+        }
+
+        for param in &mut act.params {
+            if let Parameter::DataPointer(ptr) = param {
+                *ptr = *moved.get(ptr).with_context(|| format!("action at {addr:06X}: param references non-string"))?;
+            }
+        }
+
+        act.data = data.into();
+    }
+
+    Ok(())
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let file = fs::read(&args.input)?.into();
+    let mut stcm2 = stcm2::from_bytes(file).with_context(|| format!("failed to parse {}", args.input.display()))?;
+
+    let (from, to) = (args.from.get(), args.to.get());
+
+    map_strings(&mut stcm2, from, |addr, pos, s| {
+        let Some(decoded) = from.decode_without_bom_handling_and_without_replacement(s) else {
+            crate::warn(format_args!("string at {addr:06X}+{pos:X} is not valid {}, copying it verbatim: {}", from.name(), BStr::new(s)))?;
+            return Ok(None)
+        };
+
+        let (encoded, _, replaced) = to.encode(&decoded);
+        if replaced { crate::warn(format_args!("encountered unmappable character in string at {addr:06X}+{pos:X}"))?; }
+        Ok(Some(encoded.into_owned()))
+    })?;
+
+    let out = stcm2::to_bytes(&stcm2, stcm2.variant.filler())?;
+    fs::write(args.output, out)?;
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use std::{collections::{BTreeMap, HashSet}, fs, io::{self, BufWriter, Write as _}, path::PathBuf};
+
+use anyhow::Context as _;
+use bimap::BiMap;
+use clap::Parser;
+
+use crate::{disasm::decode_with_hex_replacement, stcm2::{self, StringType}};
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(from_global)]
+    encoding: Option<super::Encoding>,
+    #[arg(long, help = "report each opcode's strings separately")]
+    by_opcode: bool,
+    file: PathBuf
+}
+
+#[derive(Default)]
+struct Stats {
+    count: usize,
+    unique: HashSet<String>,
+    chars: usize,
+    words: usize,
+    // (length in characters, action address)
+    longest: Option<(usize, u32)>,
+    shortest: Option<(usize, u32)>
+}
+
+impl Stats {
+    fn add(&mut self, addr: u32, s: String) {
+        let len = s.chars().count();
+        self.count += 1;
+        self.chars += len;
+        self.words += s.split_whitespace().count();
+        if self.longest.is_none_or(|(l, _)| len > l) {
+            self.longest = Some((len, addr));
+        }
+        if self.shortest.is_none_or(|(l, _)| len < l) {
+            self.shortest = Some((len, addr));
+        }
+        self.unique.insert(s);
+    }
+
+    fn write(&self, out: &mut impl io::Write, indent: &str) -> io::Result<()> {
+        writeln!(out, "{indent}strings: {} ({} unique)", self.count, self.unique.len())?;
+        writeln!(out, "{indent}characters: {}", self.chars)?;
+        writeln!(out, "{indent}words: {}", self.words)?;
+        if let (Some((longest, longest_addr)), Some((shortest, shortest_addr))) = (self.longest, self.shortest) {
+            writeln!(out, "{indent}average length: {:.1}", self.chars as f64 / self.count as f64)?;
+            writeln!(out, "{indent}longest: {longest} characters, at {longest_addr:06X}")?;
+            writeln!(out, "{indent}shortest: {shortest} characters, at {shortest_addr:06X}")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn main(args: Args, mnemonics: BiMap<&str, u32>) -> anyhow::Result<()> {
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    let stcm2 = stcm2::from_bytes(fs::read(&args.file)?.into()).with_context(|| format!("failed to parse {}", args.file.display()))?;
+    let encoding = args.encoding.unwrap_or_default().get();
+
+    // calls are grouped under None
+    let mut total = Stats::default();
+    let mut by_opcode = BTreeMap::<Option<u32>, Stats>::new();
+    for (&addr, act) in &stcm2.actions {
+        let strings = act.data_strings(encoding).with_context(|| format!("action at {addr:06X}"))?;
+        for (_, s) in strings {
+            let StringType::String(s) = s else { continue };
+            let s = decode_with_hex_replacement(encoding, &s).into_owned();
+            if args.by_opcode {
+                by_opcode.entry((!act.call).then_some(act.opcode)).or_default().add(addr, s.clone());
+            }
+            total.add(addr, s);
+        }
+    }
+
+    total.write(&mut stdout, "")?;
+    for (opcode, stats) in by_opcode {
+        writeln!(stdout)?;
+        match opcode {
+            None => writeln!(stdout, "calls:")?,
+            Some(opcode) => match mnemonics.get_by_right(&opcode) {
+                Some(name) => writeln!(stdout, "{name} ({opcode:X}):")?,
+                None => writeln!(stdout, "raw {opcode:X}:")?
+            }
+        }
+        stats.write(&mut stdout, "  ")?;
+    }
+
+    stdout.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use std::{collections::{BTreeMap, BTreeSet}, fmt::Write as _, fs, io::{self, BufWriter, Write as _}, path::PathBuf};
+
+use anyhow::Context as _;
+use bimap::BiMap;
+use clap::Parser;
+use saphyr::Yaml;
+
+use crate::{disasm::{chunk_actions, decode_with_hex_replacement, label_to_string, LabelEscapeStyle}, stcm2::{self, Parameter, StringType}};
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(from_global)]
+    encoding: Option<super::Encoding>,
+    #[arg(long, num_args = 2, value_names = ["START", "END"], value_parser = crate::asm::parse_hex,
+        help = "only decompile the actions from START up to END (hex addresses of action boundaries); exports aren't read")]
+    range: Option<Vec<u32>>,
+    file: PathBuf
+}
+
+// config.yaml's control_flow: { branch: N, jump: N }
+fn control_flow_opcode(confs: &[Yaml<'static>], key: &str) -> anyhow::Result<Option<u32>> {
+    let Some(control_flow) = super::config_get(confs, "control_flow") else {
+        return Ok(None)
+    };
+    let Some(v) = control_flow.as_mapping_get(key) else {
+        return Ok(None)
+    };
+    let opcode = v.as_integer().with_context(|| format!("control_flow.{key} is not an int"))?;
+    Ok(Some(opcode.try_into().with_context(|| format!("control_flow.{key} {opcode:X} out of range"))?))
+}
+
+fn render_param(param: Parameter, strings: &BTreeMap<u32, StringType>, labels: &BTreeMap<u32, String>, encoding: &'static encoding_rs::Encoding) -> anyhow::Result<String> {
+    Ok(match param {
+        Parameter::Value(v) => format!("0x{v:X}"),
+        Parameter::ActionRef(addr) => format!("&{}", labels.get(&addr).context("action ref without a label")?),
+        Parameter::GlobalDataPointer(addr) => format!("global_data[{addr}]"),
+        Parameter::DataPointer(addr) => match strings.get(&addr).context("param references non-string")? {
+            StringType::Type0U32(n) | StringType::Type1U32(n) => n.to_string(),
+            StringType::String(s) => format!("{:?}", decode_with_hex_replacement(encoding, s))
+        }
+    })
+}
+
+pub fn main(args: Args, mnemonics: BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    let file = fs::read(&args.file)?.into();
+    let actions = match args.range {
+        Some(ref range) => stcm2::parse_from_offset(file, range[0], range[1]),
+        None => stcm2::from_bytes(file).map(|stcm2| stcm2.actions)
+    }.with_context(|| format!("failed to parse {}", args.file.display()))?;
+    let encoding = args.encoding.unwrap_or_default().get();
+
+    let branch = control_flow_opcode(confs, "branch")?;
+    let jump = control_flow_opcode(confs, "jump")?;
+    let return_opcode = mnemonics.get_by_left("return").copied().unwrap_or(0);
+
+    // name everything that's called or referenced, like disasm's autolabels
+    let mut called = BTreeSet::new();
+    let mut referenced = BTreeSet::new();
+    for act in actions.values() {
+        if act.call {
+            called.insert(act.opcode);
+        }
+        for &param in &act.params {
+            if let Parameter::ActionRef(addr) = param {
+                referenced.insert(addr);
+            }
+        }
+    }
+    // targets outside a --range still get an autolabel
+    let mut labels = BTreeMap::new();
+    for (&addr, act) in &actions {
+        if let Some(label) = act.label() {
+            labels.insert(addr, label_to_string(label, LabelEscapeStyle::C).into_owned());
+        }
+    }
+    for addr in called {
+        labels.entry(addr).or_insert_with(|| format!("fn_{addr:X}"));
+    }
+    for addr in referenced {
+        labels.entry(addr).or_insert_with(|| format!("local_{addr:X}"));
+    }
+
+    let split_opcodes = super::function_terminators(&mnemonics, confs)?;
+
+    for chunk in chunk_actions(&actions, &split_opcodes) {
+        let Some(&(first, _)) = chunk.first() else { continue };
+        let name = labels.get(&first).cloned().unwrap_or_else(|| format!("sub_{first:X}"));
+        writeln!(stdout, "fn {name}() {{")?;
+
+        for (addr, act) in chunk {
+            if addr != first && let Some(label) = labels.get(&addr) {
+                writeln!(stdout, "{label}:")?;
+            }
+
+            let strings = act.data_strings(encoding).with_context(|| format!("action at {addr:06X}"))?.into_iter().collect::<BTreeMap<_, _>>();
+            let params = act.params.iter()
+                .map(|&p| render_param(p, &strings, &labels, encoding))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            let target = act.params.first().and_then(|&p| match p {
+                Parameter::ActionRef(addr) => labels.get(&addr),
+                _ => None
+            });
+
+            let mut line = String::new();
+            if act.call {
+                write!(line, "{}({});", labels.get(&act.opcode).context("call without a label")?, params.join(", "))?;
+            } else if let Some(target) = target && Some(act.opcode) == jump {
+                write!(line, "goto {target};")?;
+            } else if let Some(target) = target && Some(act.opcode) == branch {
+                write!(line, "if ({}) goto {target};", params[1..].join(", "))?;
+            } else if act.is_return(return_opcode) {
+                if params.is_empty() {
+                    write!(line, "return;")?;
+                } else {
+                    write!(line, "return {};", params.join(", "))?;
+                }
+            } else if let Some(name) = mnemonics.get_by_right(&act.opcode) {
+                write!(line, "{name}({});", params.join(", "))?;
+            } else {
+                write!(line, "op_{:X}({});", act.opcode, params.join(", "))?;
+            }
+            writeln!(stdout, "    {line}")?;
+        }
+
+        writeln!(stdout, "}}")?;
+        writeln!(stdout)?;
+    }
+
+    stdout.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::{collections::BTreeSet, fs, io::{self, BufWriter, Write as _}, path::{Path, PathBuf}};
+
+use anyhow::Context as _;
+use bstr::BStr;
+use clap::Parser;
+
+use crate::stcm2::{self, Stcm2};
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(long, help = "list every changed 4-byte word of global data")]
+    diff_global_data: bool,
+    old: PathBuf,
+    new: PathBuf
+}
+
+fn read(path: &Path) -> anyhow::Result<Stcm2> {
+    stcm2::from_bytes(fs::read(path)?.into()).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+fn word(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4).map(|w| u32::from_le_bytes(w.try_into().unwrap()))
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    let (old, new) = (read(&args.old)?, read(&args.new)?);
+
+    if old.tag != new.tag {
+        writeln!(stdout, "tag: {:?} -> {:?}", BStr::new(&old.tag), BStr::new(&new.tag))?;
+    }
+    if old.variant != new.variant {
+        writeln!(stdout, "variant: {:?} -> {:?}", old.variant, new.variant)?;
+    }
+    if old.unk_header != new.unk_header {
+        writeln!(stdout, "unk_header: {} -> {}", stcm2::hex_bytes(&old.unk_header), stcm2::hex_bytes(&new.unk_header))?;
+    }
+    if old.global_data.len() != new.global_data.len() {
+        writeln!(stdout, "global data: {} -> {} bytes", old.global_data.len(), new.global_data.len())?;
+    }
+    if old.action_count() != new.action_count() || old.code_size() != new.code_size() {
+        writeln!(stdout, "code: {} actions, {} bytes -> {} actions, {} bytes", old.action_count(), old.code_size(), new.action_count(), new.code_size())?;
+    }
+
+    let exports = |stcm2: &Stcm2| stcm2.actions.values().filter_map(|act| act.label().map(<[u8]>::to_vec)).collect::<BTreeSet<_>>();
+    let (old_exports, new_exports) = (exports(&old), exports(&new));
+    for name in old_exports.difference(&new_exports) {
+        writeln!(stdout, "- export {}", BStr::new(name))?;
+    }
+    for name in new_exports.difference(&old_exports) {
+        writeln!(stdout, "+ export {}", BStr::new(name))?;
+    }
+
+    if args.diff_global_data {
+        // offsets are in bytes, the same as [global_data+N] operands
+        let len = old.global_data.len().max(new.global_data.len());
+        for offset in (0..len).step_by(4) {
+            let (a, b) = (word(&old.global_data, offset), word(&new.global_data, offset));
+            if a == b { continue }
+            let show = |w: Option<u32>| w.map_or_else(|| "--------".to_owned(), |w| format!("{w:08X}"));
+            writeln!(stdout, "global_data+{offset}: {} -> {}", show(a), show(b))?;
+        }
+    }
+
+    stdout.flush()?;
+
+    Ok(())
+}
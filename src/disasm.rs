@@ -1,11 +1,12 @@
-use std::{borrow::Cow, cmp::Ordering, collections::{BTreeMap, BTreeSet, HashMap}, fmt::Write as _, fs, io::{self, BufWriter, Write as _}, mem, path::PathBuf, str, sync::LazyLock};
+use std::{borrow::Cow, cmp::Ordering, collections::{BTreeMap, BTreeSet}, fmt::Write as _, fs, io::{self, BufWriter, Write as _}, iter, mem, path::PathBuf, str, sync::LazyLock};
 use anyhow::{bail, ensure, Context as _};
 use bimap::BiMap;
-use bytes::{Buf as _, Bytes};
-use clap::Parser;
+use bytes::Bytes;
+use clap::{Parser, ValueEnum};
 use base64::{display::Base64Display, prelude::*};
 use encoding_rs::DecoderResult;
 use regex::bytes::{Captures, Regex};
+use saphyr::Yaml;
 
 use crate::stcm2::*;
 
@@ -14,98 +15,55 @@ pub struct Args {
     #[arg(short = 'a', help = "print addresses in disassembly")]
     address: bool,
     #[arg(from_global)]
-    encoding: super::Encoding,
+    encoding: Option<super::Encoding>,
     #[arg(short = 'j', help = "print binary junk data (for reproducible files)")]
     junk: bool,
+    #[arg(long, value_name = "N", help = "cap the label column at N characters, truncating longer labels with …")]
+    max_label_display_width: Option<usize>,
+    #[arg(long, value_enum, default_value_t = LabelEscapeStyle::Asm, help = "how to escape unusual label bytes (only asm can be reassembled)")]
+    label_escape_style: LabelEscapeStyle,
+    #[arg(long, help = "show action refs passed to config.yaml's indirect_call_opcodes as call operands")]
+    semantic_calls: bool,
+    #[arg(long, value_name = "N", help = "split junk base64 into lines of N characters joined with \\ continuations")]
+    wrap_junk: Option<usize>,
+    #[arg(long, help = "print values of 80000000 and above as negative decimals")]
+    signed: bool,
+    #[arg(long, value_name = "N", help = "print =N integers at or above N in hex (default: config.yaml's int_hex_threshold, else 10000000h; 0 for always)")]
+    int_hex_threshold: Option<u32>,
+    #[arg(long, help = "end lines with the file offset of each string or =N operand (output can't be reassembled)")]
+    show_data_offsets: bool,
+    #[arg(long, help = "end lines with the file offset of each parameter, and of the data it points to (output can't be reassembled)")]
+    show_param_addresses: bool,
+    #[arg(long, value_name = "N", help = "warn about strings longer than N bytes as encoded (see --abort-on-warning to make it an error)")]
+    max_string_length: Option<usize>,
+    #[arg(long, requires = "max_string_length", help = "measure --max-string-length in code points instead of bytes")]
+    measure_codepoints: bool,
+    #[arg(long, help = "order functions by their first label instead of by address (reassembles to a reordered file)")]
+    sort_exports: bool,
+    #[arg(long, help = "leave out the label column (output can't be reassembled)")]
+    omit_labels: bool,
+    #[arg(long, value_name = "s/PATTERN/REPLACEMENT/", help = "rename labels for reading, noting the original name after each (output can't be reassembled)")]
+    demangle_regex: Option<String>,
+    #[arg(long, help = "replace labels with non-ASCII bytes by fn_ADDR, noting the original after each (output can't be reassembled)")]
+    ascii_only_labels: bool,
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "ENCODINGS",
+        help = "decode strings that aren't valid in the main encoding with the first of these they are valid in, marking the line with ; encoding: NAME")]
+    encoding_fallback: Vec<super::Encoding>,
+    #[arg(long, help = "check that the output reassembles, failing if it doesn't")]
+    check_format: bool,
+    #[arg(long, help = "leave out the .tag, .global_data, .unk_header and .code_start lines (output can't be reassembled)")]
+    no_header: bool,
+    #[arg(long, conflicts_with = "no_header", help = "print only the .tag, .global_data, .unk_header and .code_start lines")]
+    only_header: bool,
     file: PathBuf
 }
 
-#[derive(Debug, Clone)]
-enum StringType {
-    String(Bytes),
-    Type0U32(u32),
-    Type1U32(u32)
-}
-
-impl StringType {
-    fn type_(&self) -> u32 {
-        match self {
-            Self::String(_) | Self::Type0U32(_) => 0,
-            Self::Type1U32(_) => 1
-        }
-    }
-}
-
-// Technically a 2-3 byte heuristic
-fn four_byte_heuristic(encoding: &'static encoding_rs::Encoding, mut v: Bytes) -> StringType {
-    assert_eq!(v.len(), 4);
-
-    let n = v[..].try_into().map(u32::from_le_bytes).unwrap();
-
-    if n > 0xFFFFFF {
-        return StringType::Type0U32(n)
-    }
-
-    let nzero = v.iter().rev().take_while(|&&n| n == 0).count();
-
-    v.truncate(v.len() - nzero);
-
-    if v.len() < 3 || v[..] == *b"op" {
-        return StringType::Type0U32(n)
-    }
-
-    let Some(s) = encoding.decode_without_bom_handling_and_without_replacement(&v) else {
-        return StringType::Type0U32(n)
-    };
-
-    if s.chars().any(char::is_control) {
-        return StringType::Type0U32(n)
-    }
-
-    StringType::String(v)
-}
-
-fn decode_string(encoding: &'static encoding_rs::Encoding, addr: u32, mut str: Bytes) -> anyhow::Result<(StringType, Bytes)> {
-    str.advance(addr as usize);
-
-    ensure!(str.len() > 16, "not enough room for magic");
-
-    let type_ = str.get_u32_le();
-    ensure!(matches!(type_, 0 | 1), "string magic isn't 0 or 1");
-    let qlen = str.get_u32_le();
-    ensure!(str.get_u32_le() == 1, "string magic isn't 1");
-    let len = str.get_u32_le();
-    ensure!(len == qlen*4, "len and qlen are inconsistent: len = {len}, qlen = {qlen}");
-    let len = len.try_into()?;
-
-    ensure!(str.len() >= len, "not enough room for string data");
-
-    let tail = str.split_off(len);
-
-    if type_ == 1 && let Ok(n) = str[..].try_into().map(u32::from_le_bytes) {
-        return Ok((StringType::Type1U32(n), tail))
-    }
-
-    if str.len() == 4 {
-        return Ok((four_byte_heuristic(encoding, str), tail))
-    }
-
-    ensure!(type_ == 0, "string type is 1, but is not a u32");
-
-    // clip zeros off end
-    let nzero = str.iter().rev().take_while(|&&n| n == 0).count();
-    ensure!(matches!(nzero, 1..=4), "string is not canonical");
-    str.truncate(str.len() - nzero);
-
-    Ok((StringType::String(str), tail))
-}
-
 fn autolabel(prefix: &str, addr: u32) -> Bytes {
     let label = format!("{prefix}_{addr:X}");
     label.into_bytes().into()
 }
 
-fn decode_with_hex_replacement<'a>(encoding: &'static encoding_rs::Encoding, mut buf: &'a [u8]) -> Cow<'a, str> {
+pub fn decode_with_hex_replacement<'a>(encoding: &'static encoding_rs::Encoding, mut buf: &'a [u8]) -> Cow<'a, str> {
     const RESERVE: usize = char::MAX.len_utf8();
 
     if let Some(buf) = encoding.decode_without_bom_handling_and_without_replacement(buf) {
@@ -142,22 +100,69 @@ fn cow_bytes_to_str(bytes: Cow<'_, [u8]>) -> Option<Cow<'_, str>> {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LabelEscapeStyle {
+    /// \xNN, readable by the assembler
+    Asm,
+    /// _xNN_ for anything that isn't a C identifier character
+    C,
+    /// _uNNNN_ for anything that isn't a Python identifier character
+    Python,
+    /// %NN for anything that isn't printable ASCII (and for % itself)
+    Hex
+}
+
+// Parse a sed-style s/pattern/replacement/, turning \1 in the replacement into ${1}
+fn parse_substitution(substitution: &str) -> anyhow::Result<(Regex, String)> {
+    let mut chars = substitution.chars();
+    ensure!(chars.next() == Some('s'), "substitution {substitution:?} does not start with s");
+    let delim = chars.next().with_context(|| format!("substitution {substitution:?} has no delimiter"))?;
+    let parts = chars.as_str().split(delim).collect::<Vec<_>>();
+    let [pattern, replacement, ""] = parts[..] else {
+        bail!("substitution {substitution:?} is not of the form s{delim}pattern{delim}replacement{delim}");
+    };
+
+    static BACKREF: LazyLock<regex::Regex> = LazyLock::new(|| regex::Regex::new(r"\\(\d+)").unwrap());
+    let replacement = BACKREF.replace_all(replacement, "$${$1}").into_owned();
+    Ok((Regex::new(pattern)?, replacement))
+}
+
+// With -j, keep whatever follows the null in an export so asm writes it back
+fn export_label(act: &Action, junk: bool) -> Option<&[u8]> {
+    if junk { act.label_with_junk() } else { act.label() }
+}
+
 // Always interpret labels as ASCII
-fn label_to_string(label: &[u8]) -> Cow<'_, str> {
+pub fn label_to_string(label: &[u8], style: LabelEscapeStyle) -> Cow<'_, str> {
     static ILLEGAL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?-u:[^!-\[\]-~])").unwrap());
+    static NON_IDENT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?-u:[^0-9A-Za-z_])").unwrap());
+    static NON_HEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?-u:[^!-\[\]-~]|%)").unwrap());
 
-    cow_bytes_to_str(ILLEGAL.replace_all(label, |c: &Captures<'_>| {
+    let illegal = match style {
+        LabelEscapeStyle::Asm => &ILLEGAL,
+        LabelEscapeStyle::C | LabelEscapeStyle::Python => &NON_IDENT,
+        LabelEscapeStyle::Hex => &NON_HEX
+    };
+
+    cow_bytes_to_str(illegal.replace_all(label, |c: &Captures<'_>| {
         let substr = c.get(0).unwrap().as_bytes();
         assert_eq!(substr.len(), 1);
         // evil miniscule heap allocation LOL
-        format!("\\x{:02x}", substr[0])
+        match style {
+            LabelEscapeStyle::Asm => format!("\\x{:02x}", substr[0]),
+            LabelEscapeStyle::C => format!("_x{:02x}_", substr[0]),
+            LabelEscapeStyle::Python => format!("_u{:04x}_", substr[0]),
+            LabelEscapeStyle::Hex => format!("%{:02X}", substr[0])
+        }
     })).unwrap()
 }
 
-// Heuristically determine function boundaries by splitting on returns,
+// Heuristically determine function boundaries by splitting on returns (or other split_opcodes),
 // and combining based on labels and local jumps.
 // This could probably be more efficient using u32 ranges to represent chunks
-fn chunk_actions(acts: &BTreeMap<u32, Action>) -> Vec<Vec<(u32, &Action)>> {
+// Chunks only affect blank-line layout; every label is printed on its own action's line,
+// so a label right after a return stays attached to the action it names.
+pub fn chunk_actions<'a>(acts: &'a BTreeMap<u32, Action>, split_opcodes: &[u32]) -> Vec<Vec<(u32, &'a Action)>> {
     let mut chunks = Vec::new();
     let mut current_labels = BTreeSet::new(); // More performant than HashSet
     let mut current_chunk = Vec::new();
@@ -170,7 +175,7 @@ fn chunk_actions(acts: &BTreeMap<u32, Action>) -> Vec<Vec<(u32, &Action)>> {
             }
         }
         current_chunk.push((addr, act));
-        if !act.call && act.opcode == 0 {
+        if split_opcodes.iter().any(|&op| act.is_return(op)) {
             chunks.push((
                 mem::take(&mut current_labels),
                 mem::take(&mut current_chunk)
@@ -205,11 +210,71 @@ fn chunk_actions(acts: &BTreeMap<u32, Action>) -> Vec<Vec<(u32, &Action)>> {
     chunks.into_iter().map(|z| z.1).collect()
 }
 
-pub fn main(args: Args, mnemonics: BiMap<&str, u32>) -> anyhow::Result<()> {
-    let mut stdout = BufWriter::new(io::stdout().lock());
-    let file = fs::read(args.file)?.into();
+// Passes writes through, keeping a copy when there is one to keep
+struct Tee<W> {
+    inner: W,
+    copy: Option<Vec<u8>>
+}
+
+impl<W: io::Write> io::Write for Tee<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        if let Some(ref mut copy) = self.copy {
+            copy.extend_from_slice(&buf[..n]);
+        }
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+// The disassembly of a whole file, as text
+fn disassemble(args: &Args, file: Bytes, mnemonics: &BiMap<&str, u32>, confs: &[Yaml<'static>], stdout: &mut impl io::Write) -> anyhow::Result<()> {
+    let file_len = file.len();
+
+    let mut stcm2 = from_bytes_with_export_name_length(file, super::export_name_length(confs)?).with_context(|| format!("failed to parse {}", args.file.display()))?;
+
+    // something outside this tool truncated or padded the file
+    if let Some(recorded) = stcm2.recorded_file_len && usize::try_from(recorded)? != file_len {
+        super::warn(format_args!("file is {file_len:#x} bytes, but its collection link records {recorded:#x}"))?;
+    }
+
+    for (addr, act) in &stcm2.actions {
+        if let Some(ref export) = act.export && !export.contains(&0) {
+            super::warn(format_args!("export at {addr:06X} is not null-terminated: {}", label_to_string(export, args.label_escape_style)))?;
+        }
+        // labels are always escaped byte-by-byte, so show what a non-ASCII name was probably meant to say
+        if let Some(ref export) = act.export && let name = export.split(|&b| b == 0).next().unwrap_or_default() && !name.is_ascii() {
+            let encoding = args.encoding.unwrap_or_default().get();
+            super::warn(format_args!("export at {addr:06X} contains non-ASCII bytes: {} (as {}: {:?}; try a different --encoding if that looks wrong)",
+                label_to_string(name, args.label_escape_style), encoding.name(), decode_with_hex_replacement(encoding, name)))?;
+        }
+    }
+
+    if let Some(allowed) = super::allowed_opcodes(confs)? {
+        for (addr, act) in &stcm2.actions {
+            if !act.call && !allowed.iter().any(|r| r.contains(&act.opcode)) {
+                super::warn(format_args!("action at {addr:06X} has opcode {:X}, which is outside config.yaml's allowed_opcodes", act.opcode))?;
+            }
+        }
+    }
 
-    let mut stcm2 = from_bytes(file)?;
+    let signatures = super::opcode_signatures(mnemonics, confs)?;
+    for (addr, act) in &stcm2.actions {
+        if !act.call && let Some(&nparams) = signatures.get(&act.opcode) && act.params.len() != nparams {
+            super::warn(format_args!("action at {addr:06X} has {} params, but config.yaml says opcode {:X} takes {nparams}", act.params.len(), act.opcode))?;
+        }
+    }
+
+    // the first param of these opcodes is a function pointer rather than a jump target
+    let indirect_call_opcodes = if args.semantic_calls {
+        super::parse_opcode_list(confs, "indirect_call_opcodes")?.unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let is_indirect_call = |act: &Action, i: usize| i == 0 && !act.call && indirect_call_opcodes.contains(&act.opcode);
 
     // build symbol table and autolabels
     let mut autolabels = BTreeMap::new();
@@ -222,12 +287,16 @@ pub fn main(args: Args, mnemonics: BiMap<&str, u32>) -> anyhow::Result<()> {
                 *ent = autolabel("fn", opcode);
             }
         }
-        for &param in &act.params {
+        for (i, &param) in act.params.iter().enumerate() {
             if let Parameter::ActionRef(addr) = param
                 && stcm2.actions.get(&addr).context("bruh9")?.export.is_none()
             {
-                let ent = autolabels.entry(addr).or_default();
-                if ent.is_empty() {
+                let ent: &mut Bytes = autolabels.entry(addr).or_default();
+                if is_indirect_call(act, i) {
+                    if !ent.starts_with(b"fn") {
+                        *ent = autolabel("fn", addr);
+                    }
+                } else if ent.is_empty() {
                     *ent = autolabel("local", addr);
                 }
             }
@@ -249,22 +318,98 @@ pub fn main(args: Args, mnemonics: BiMap<&str, u32>) -> anyhow::Result<()> {
         }
     }
 
-    let tag = str::from_utf8(&stcm2.tag).context("nooooo")?.trim_end_matches('\0');
-    writeln!(stdout, ".tag \"{tag}\"")?;
-    writeln!(stdout, ".global_data {}", Base64Display::new(&stcm2.global_data, &BASE64_STANDARD_NO_PAD))?;
-    writeln!(stdout, ".code_start")?;
+    // rename labels for display, remembering what they were called
+    let mut demangled_from = BTreeMap::new();
+    if let Some(ref substitution) = args.demangle_regex {
+        let (pattern, replacement) = parse_substitution(substitution)?;
+        for (&addr, act) in &mut stcm2.actions {
+            let Some(label) = export_label(act, args.junk) else { continue };
+            let renamed = pattern.replace_all(label, replacement.as_bytes());
+            if renamed[..] != *label {
+                let renamed = Bytes::from(renamed.into_owned());
+                demangled_from.insert(addr, label_to_string(label, args.label_escape_style).into_owned());
+                act.export = Some(renamed);
+            }
+        }
+    }
+    if args.ascii_only_labels {
+        for (&addr, act) in &mut stcm2.actions {
+            let Some(label) = export_label(act, args.junk) else { continue };
+            if !label.is_ascii() {
+                demangled_from.entry(addr).or_insert_with(|| label_to_string(label, args.label_escape_style).into_owned());
+                act.export = Some(autolabel("fn", addr));
+            }
+        }
+    }
+
+    if !args.no_header {
+        // asm picks this up when no -e is given, so strings aren't silently re-encoded as UTF-8
+        if let Some(encoding) = args.encoding && encoding != super::Encoding::default() {
+            writeln!(stdout, "; [encoding: {}]", encoding.to_possible_value().context("no encoding name")?.get_name())?;
+        }
+
+        let tag = str::from_utf8(&stcm2.tag).context("nooooo")?.trim_end_matches('\0');
+        if Variant::from_tag(tag.as_bytes()) == stcm2.variant {
+            writeln!(stdout, ".tag \"{tag}\"")?;
+        } else {
+            // the tag alone doesn't say which variant this is (an STCM2L\0 magic), so spell it out
+            writeln!(stdout, ".tag \"{tag}\" {}", stcm2.variant.to_possible_value().context("no variant name")?.get_name())?;
+        }
+        writeln!(stdout, ".global_data {}", Base64Display::new(&stcm2.global_data, &BASE64_STANDARD_NO_PAD))?;
+        // asm fills in zeros when this is left out
+        if stcm2.unk_header.iter().any(|&b| b != 0) {
+            writeln!(stdout, ".unk_header {}", Base64Display::new(&stcm2.unk_header, &BASE64_STANDARD_NO_PAD))?;
+        }
+        if stcm2.export_name_length != EXPORT_NAME_LENGTH {
+            writeln!(stdout, ".export_name_length {}", stcm2.export_name_length)?;
+        }
+        writeln!(stdout, ".code_start")?;
+    }
+    if args.only_header {
+        stdout.flush()?;
+        return Ok(())
+    }
 
-    let maxlabel = stcm2.actions.values().filter_map(|act| act.label(args.junk)).map(|l| l.len()).max().unwrap_or_default().max(14);
+    let maxlabel = stcm2.actions.values().filter_map(|act| export_label(act, args.junk)).map(|l| l.len()).max().unwrap_or_default().max(14);
+    let maxlabel = args.max_label_display_width.map_or(maxlabel, |w| maxlabel.min(w.max(1)));
+    // continuation lines are indented to line up with the label column, if there is one
+    let maxlabel = if args.omit_labels { 0 } else { maxlabel };
+
+    let split_opcodes = super::function_terminators(mnemonics, confs)?;
+
+    let int_hex_threshold = match args.int_hex_threshold {
+        Some(n) => n,
+        None => match super::config_get(confs, "int_hex_threshold") {
+            Some(v) => {
+                let n = v.as_integer().context("int_hex_threshold is not an int")?;
+                n.try_into().with_context(|| format!("int_hex_threshold {n:X} out of range"))?
+            },
+            None => 0x10000000
+        }
+    };
+
+    let mut chunks = chunk_actions(&stcm2.actions, &split_opcodes);
+    if args.sort_exports {
+        // chunks end at a terminator, so they can be reordered without changing what falls through
+        // unlabelled (unreachable) chunks go last, in their original order
+        chunks.sort_by_key(|chunk| chunk.iter().find_map(|(_, act)| export_label(act, args.junk)).map_or((true, None), |l| (false, Some(l))));
+    }
 
-    for chunk in chunk_actions(&stcm2.actions) {
+    for chunk in chunks {
         writeln!(stdout)?;
         for (addr, act) in chunk {
             if args.address {
                 write!(stdout, "{addr:06X} ")?;
             }
 
-            if let Some(label) = act.label(args.junk) {
-                let label = label_to_string(label);
+            if args.omit_labels {
+                // no label column at all, for grepping
+            } else if let Some(label) = export_label(act, args.junk) {
+                let mut label = label_to_string(label, args.label_escape_style);
+                if args.max_label_display_width.is_some() && label.chars().count() > maxlabel {
+                    // display only; references elsewhere keep the full label
+                    label = label.chars().take(maxlabel - 1).chain(iter::once('…')).collect();
+                }
                 write!(stdout, "{label:>maxlabel$}: ")?;
             } else {
                 write!(stdout, "{:maxlabel$}  ", "")?;
@@ -273,62 +418,60 @@ pub fn main(args: Args, mnemonics: BiMap<&str, u32>) -> anyhow::Result<()> {
             let Action { call, opcode, ref params, ref data, .. } = *act;
             
             if call {
-                write!(stdout, "call {}", label_to_string(stcm2.actions.get(&opcode).context("bruh")?.label(args.junk).context("bruh2")?))?;
+                write!(stdout, "call {}", label_to_string(export_label(stcm2.actions.get(&opcode).context("bruh")?, args.junk).context("bruh2")?, args.label_escape_style))?;
             } else if let Some(name) = mnemonics.get_by_right(&opcode) {
                 write!(stdout, "{name}")?;
             } else {
                 write!(stdout, "raw {opcode:X}")?;
             }
 
-            let mut data = data.clone();
-            let mut pos = 0;
-            let mut junk = Bytes::new();
-
-            let mut at_beginning = true;
-
-            let mut data_pos = HashMap::new();
-
-            while pos < data.len() {
-                if let Ok((s, tail)) = decode_string(args.encoding.get(), pos.try_into()?, data.clone()) {
-                    if pos != 0 {
-                        ensure!(at_beginning, "junk found after beginning");
-                        junk = data.slice(..pos);
-                    }
-                    at_beginning = false;
-
-                    let abs_pos = pos + ((data.as_ptr() as usize) - (act.data.as_ptr() as usize));
-                    data_pos.insert(abs_pos, s);
-
-                    data = tail;
-                    pos = 0;
-                    continue;
-                }
-
-                pos += 1;
-            }
-
-            if !data.is_empty() {
-                ensure!(at_beginning, "junk found after beginning");
-                junk = data;
-            }
-
-            for &param in params {
+            let data_pos = act.data_strings(args.encoding.unwrap_or_default().get()).with_context(|| format!("action at {addr:06X}"))?.into_iter().collect::<BTreeMap<_, _>>();
+            // all of an action's strings share one encoding, so asm can be told it with one marker
+            let main_encoding = args.encoding.unwrap_or_default();
+            let line_encoding = iter::once(main_encoding).chain(args.encoding_fallback.iter().copied())
+                .find(|e| data_pos.values().all(|s| match s {
+                    StringType::String(s) => e.get().decode_without_bom_handling_and_without_replacement(s).is_some(),
+                    _ => true
+                }))
+                .unwrap_or(main_encoding);
+            // anything before the first string is junk
+            let junk = data.slice(..data_pos.first_key_value().map_or(data.len(), |(&pos, _)| pos as usize));
+
+            for (i, &param) in params.iter().enumerate() {
                 match param {
+                    Parameter::Value(v) if args.signed && v >= 0x80000000 => write!(stdout, ", {}", v as i32)?,
                     Parameter::Value(v) => write!(stdout, ", {v:X}")?,
-                    Parameter::ActionRef(addr) => write!(stdout, ", [{}]", label_to_string(stcm2.actions.get(&addr).context("bruh5")?.label(args.junk).context("bruh6")?))?,
-                    Parameter::DataPointer(addr) => {
-                        if let Some(s) = data_pos.get(&usize::try_from(addr)?) {
+                    Parameter::ActionRef(addr) => {
+                        let label = label_to_string(export_label(stcm2.actions.get(&addr).context("bruh5")?, args.junk).context("bruh6")?, args.label_escape_style);
+                        if is_indirect_call(act, i) {
+                            write!(stdout, ", call {label}")?;
+                        } else {
+                            write!(stdout, ", [{label}]")?;
+                        }
+                    },
+                    Parameter::DataPointer(ptr) => {
+                        if let Some(s) = data_pos.get(&ptr) {
                             match *s {
                                 ref s@StringType::Type0U32(n) | ref s@StringType::Type1U32(n) => {
+                                    // =N for type 0, @=N for type 1; hex (with an h suffix) for large values
                                     let prefix = if s.type_() == 0 { "" } else { "@" };
-                                    if n < 0x10000000 {
+                                    if n < int_hex_threshold {
                                         write!(stdout, ", {prefix}={n}")?;
                                     } else {
                                         write!(stdout, ", {prefix}={n:X}h")?;
                                     }
                                 },
                                 StringType::String(ref s) => {
-                                    let s   = decode_with_hex_replacement(args.encoding.get(), s);
+                                    let raw_len = s.len();
+                                    let s   = decode_with_hex_replacement(line_encoding.get(), s);
+                                    if let Some(max) = args.max_string_length {
+                                        let len = if args.measure_codepoints { s.chars().count() } else { raw_len };
+                                        if len > max {
+                                            let unit = if args.measure_codepoints { "code points" } else { "bytes" };
+                                            let preview = s.chars().take(40).collect::<String>();
+                                            super::warn(format_args!("string in action at {addr:06X} is {} {unit} over the limit of {max}: {preview:?}", len - max))?;
+                                        }
+                                    }
                                     write!(stdout, ", \"")?;
                                     for ch in s.chars() {
                                         if ch.is_control() {
@@ -353,14 +496,164 @@ pub fn main(args: Args, mnemonics: BiMap<&str, u32>) -> anyhow::Result<()> {
             }
 
             if args.junk && !junk.is_empty() {
-                write!(stdout, " ! {}", Base64Display::new(&junk[..], &BASE64_STANDARD_NO_PAD))?;
+                let junk = BASE64_STANDARD_NO_PAD.encode(&junk);
+                match args.wrap_junk {
+                    Some(n) if n > 0 => {
+                        // base64 is all ASCII, so chunking bytes never splits a character
+                        let mut chunks = junk.as_bytes().chunks(n).map(|c| str::from_utf8(c).unwrap());
+                        write!(stdout, " ! {}", chunks.next().unwrap())?;
+                        for chunk in chunks {
+                            write!(stdout, "\\\n{:maxlabel$}    {chunk}", "")?;
+                        }
+                    },
+                    _ => write!(stdout, " ! {junk}")?
+                }
+            }
+
+            if args.show_data_offsets {
+                // action addresses are file offsets, and the data follows the header and params
+                let data_base = addr + u32::try_from(16 + 12*params.len())?;
+                let mut offsets = act.data_references().map(|ptr| data_base + ptr).peekable();
+                if offsets.peek().is_some() {
+                    write!(stdout, " ;")?;
+                    for offset in offsets {
+                        write!(stdout, " @0x{offset:06X}")?;
+                    }
+                }
+            }
+
+            if args.show_param_addresses && !params.is_empty() {
+                // params are 12 bytes each, right after the 16-byte action header
+                let data_base = addr + u32::try_from(16 + 12*params.len())?;
+                write!(stdout, " ;")?;
+                for (i, &param) in (0u32..).zip(params) {
+                    write!(stdout, " param@0x{:06X}", addr + 16 + 12*i)?;
+                    if let Parameter::DataPointer(ptr) = param {
+                        write!(stdout, "->0x{:06X}", data_base + ptr)?;
+                    }
+                }
+            }
+
+            if let Some(original) = demangled_from.get(&addr) {
+                write!(stdout, " ; original: {original}")?;
+            }
+
+            // asm only looks for this at the very end of a line
+            if line_encoding != main_encoding {
+                write!(stdout, " ; encoding: {}", line_encoding.to_possible_value().context("no encoding name")?.get_name())?;
             }
 
             writeln!(stdout)?;
+
+            if act.padding != 0 {
+                if args.address {
+                    write!(stdout, "{:06X} ", addr + u32::try_from(act.len())?)?;
+                }
+                if args.omit_labels {
+                    writeln!(stdout, ".padding {}", act.padding)?;
+                } else {
+                    writeln!(stdout, "{:maxlabel$}  .padding {}", "", act.padding)?;
+                }
+            }
         }
     }
 
+    Ok(())
+}
+
+pub fn main(args: Args, mnemonics: BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    let mut stdout = BufWriter::new(Tee { inner: io::stdout().lock(), copy: args.check_format.then(Vec::new) });
+    disassemble(&args, fs::read(&args.file)?.into(), &mnemonics, confs, &mut stdout)?;
     stdout.flush()?;
 
+    // everything has been printed by now, so a failure here points at the output above
+    if let Some(copy) = stdout.into_inner().map_err(|e| e.into_error())?.copy {
+        crate::asm::check_source(&String::from_utf8(copy)?, &mnemonics, confs).context("disassembly does not reassemble")?;
+    }
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#".tag "TEST"
+.global_data AAAAAAAAAAA
+.code_start
+main: raw 10, 1, "hello", =5, @=FFFFFFFFh, [global_data+4]
+call helper, [data]
+return
+helper: raw 20, "x\"y\\z", "hello"
+.padding 8
+return
+data: raw 30, FFFFFFFF ! AAAAAA
+return
+"#;
+
+    fn assemble(text: &str, mnemonics: &BiMap<&str, u32>) -> Vec<u8> {
+        let (stcm2, filler) = crate::asm::build_text(&[], text, mnemonics, &[]).unwrap();
+        crate::stcm2::to_bytes(&stcm2, filler).unwrap()
+    }
+
+    fn disassemble_with_junk(file: Vec<u8>, mnemonics: &BiMap<&str, u32>) -> String {
+        let crate::Args { cmd: crate::Command::Disasm(args), .. } = crate::Args::try_parse_from(["stcm2-asm", "disasm", "-j", "<test>"]).unwrap() else {
+            unreachable!()
+        };
+        let mut out = Vec::new();
+        disassemble(&args, file.into(), mnemonics, &[], &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn labels_right_after_return_round_trip() {
+        let mnemonics = BiMap::from_iter([("return", 0)]);
+        let source = ".tag \"TEST\"\n.global_data AAAAAAAAAAA\n.code_start\nmain: raw 1, [local_1]\nreturn\nlocal_1: raw 2\nreturn\nnamed: return\n";
+        let file = assemble(source, &mnemonics);
+        let text = disassemble_with_junk(file.clone(), &mnemonics);
+
+        // each label stays on the action after the return, not on the return or on its own line
+        let lines = text.lines().map(str::trim).filter(|l| !l.is_empty()).collect::<Vec<_>>();
+        let local = lines.iter().position(|l| l.starts_with("local_")).unwrap();
+        assert_eq!(lines[local - 1], "return");
+        assert!(lines[local].ends_with(": raw 2"), "{text}");
+        let named = lines.iter().position(|l| l.starts_with("named:")).unwrap();
+        assert_eq!(lines[named - 1], "return");
+        assert_eq!(lines[named], "named: return");
+
+        assert_eq!(assemble(&text, &mnemonics), file);
+    }
+
+    #[test]
+    fn round_trips_give_identical_text() {
+        let mnemonics = BiMap::from_iter([("return", 0)]);
+        let first = disassemble_with_junk(assemble(SOURCE, &mnemonics), &mnemonics);
+        assert!(first.contains("helper: raw 20") && first.contains(".padding 8") && first.contains("! AAAAAA"), "{first}");
+        let second = disassemble_with_junk(assemble(&first, &mnemonics), &mnemonics);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn junk_after_export_null_round_trips() {
+        let mnemonics = BiMap::from_iter([("return", 0)]);
+        let source = ".tag \"TEST\"\n.global_data AAAAAAAAAAA\n.code_start\nmain\\x00garbage: return\n";
+        let file = assemble(source, &mnemonics);
+        assert!(file.windows(12).any(|w| w == b"main\0garbage"));
+
+        let text = disassemble_with_junk(file.clone(), &mnemonics);
+        assert!(text.contains("main\\x00garbage:"), "{text}");
+        assert_eq!(assemble(&text, &mnemonics), file);
+    }
+
+    #[test]
+    fn wide_export_names_round_trip() {
+        let mnemonics = BiMap::from_iter([("return", 0)]);
+        let label = "a_label_that_is_too_long_for_the_usual_field";
+        let source = format!(".tag \"TEST\"\n.global_data AAAAAAAAAAA\n.export_name_length 64\n.code_start\n{label}: return\n");
+        let file = assemble(&source, &mnemonics);
+
+        let text = disassemble_with_junk(file.clone(), &mnemonics);
+        assert!(text.contains(".export_name_length 64\n") && text.contains(label), "{text}");
+        assert_eq!(assemble(&text, &mnemonics), file);
+    }
+}
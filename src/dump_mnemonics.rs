@@ -0,0 +1,54 @@
+use std::io::{self, BufWriter, Write as _};
+
+use anyhow::Context as _;
+use bimap::BiMap;
+use clap::Parser;
+use saphyr::Yaml;
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(long, help = "print an HTML table instead of Markdown")]
+    html: bool
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+pub fn main(args: Args, mnemonics: BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    let mut stdout = BufWriter::new(io::stdout().lock());
+
+    // config.yaml's descriptions: { mnemonic: "what it does" }
+    let descriptions = super::config_get(confs, "descriptions");
+    if let Some(descriptions) = descriptions {
+        descriptions.as_mapping().context("descriptions is not a mapping")?;
+    }
+    let description = |name: &str| -> anyhow::Result<&str> {
+        match descriptions.and_then(|d| d.as_mapping_get(name)) {
+            Some(v) => v.as_str().with_context(|| format!("description of {name} is not a str")),
+            None => Ok("")
+        }
+    };
+
+    let mut mnemonics = mnemonics.into_iter().collect::<Vec<_>>();
+    mnemonics.sort_by_key(|&(name, opcode)| (opcode, name));
+
+    if args.html {
+        writeln!(stdout, "<table>")?;
+        writeln!(stdout, "<tr><th>Mnemonic</th><th>Opcode (hex)</th><th>Description</th></tr>")?;
+        for (name, opcode) in mnemonics {
+            writeln!(stdout, "<tr><td><code>{}</code></td><td>{opcode:X}</td><td>{}</td></tr>", escape_html(name), escape_html(description(name)?))?;
+        }
+        writeln!(stdout, "</table>")?;
+    } else {
+        writeln!(stdout, "| Mnemonic | Opcode (hex) | Description |")?;
+        writeln!(stdout, "|---|---|---|")?;
+        for (name, opcode) in mnemonics {
+            writeln!(stdout, "| `{name}` | {opcode:X} | {} |", description(name)?.replace('|', "\\|"))?;
+        }
+    }
+
+    stdout.flush()?;
+
+    Ok(())
+}
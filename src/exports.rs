@@ -0,0 +1,102 @@
+use std::{fs, io::{self, BufWriter, Write as _}, path::PathBuf};
+
+use anyhow::Context as _;
+use bstr::BStr;
+use clap::Parser;
+
+use crate::stcm2;
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(long = "match", value_name = "PATTERN", help = "only list exports matching a glob pattern (* and ?; may be repeated)")]
+    patterns: Vec<String>,
+    file: PathBuf
+}
+
+// Shell-style wildcard match: * is any run of bytes, ? is any one byte
+fn wildcard_match(pattern: &[u8], name: &[u8]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    // where to resume if the last * has to swallow another byte
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, n));
+                p += 1;
+            },
+            Some(&c) if c == b'?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            },
+            _ => {
+                let Some((sp, sn)) = star else { return false };
+                p = sp + 1;
+                n = sn + 1;
+                star = Some((sp, n));
+            }
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    let stcm2 = stcm2::from_bytes(fs::read(&args.file)?.into()).with_context(|| format!("failed to parse {}", args.file.display()))?;
+
+    let code_end = stcm2.actions.last_key_value().map_or(0, |(&addr, act)| addr as usize + act.len() + act.padding);
+    let exports = stcm2.actions.iter().filter_map(|(&addr, act)| Some((addr, act.label()?))).collect::<Vec<_>>();
+
+    // each export's range runs up to the next export
+    for (i, &(addr, label)) in exports.iter().enumerate() {
+        if !args.patterns.is_empty() && !args.patterns.iter().any(|p| wildcard_match(p.as_bytes(), label)) {
+            continue
+        }
+        let end = exports.get(i + 1).map_or(code_end, |&(next, _)| next as usize);
+        writeln!(stdout, "{addr:06X}-{end:06X} {}", BStr::new(label))?;
+    }
+
+    stdout.flush()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::wildcard_match;
+
+    #[test]
+    fn literal_patterns_match_exactly() {
+        assert!(wildcard_match(b"main", b"main"));
+        assert!(!wildcard_match(b"main", b"mains"));
+        assert!(!wildcard_match(b"main", b"mai"));
+        assert!(wildcard_match(b"", b""));
+        assert!(!wildcard_match(b"", b"main"));
+        // labels aren't necessarily UTF-8
+        assert!(wildcard_match(b"fn_\xff", b"fn_\xff"));
+    }
+
+    #[test]
+    fn star_matches_any_run() {
+        assert!(wildcard_match(b"*", b""));
+        assert!(wildcard_match(b"*", b"anything"));
+        assert!(wildcard_match(b"fn_*", b"fn_"));
+        assert!(wildcard_match(b"fn_*", b"fn_1234"));
+        assert!(!wildcard_match(b"fn_*", b"local_1234"));
+        assert!(wildcard_match(b"*_end", b"scene_1_end"));
+        assert!(wildcard_match(b"a*b*c", b"aXbYbZc"));
+        assert!(!wildcard_match(b"a*b*c", b"aXbYbZ"));
+        // the * has to backtrack past a partial match of what follows it
+        assert!(wildcard_match(b"*aab", b"aaab"));
+        assert!(wildcard_match(b"**", b"x"));
+    }
+
+    #[test]
+    fn question_mark_matches_one_byte() {
+        assert!(wildcard_match(b"fn_?", b"fn_1"));
+        assert!(!wildcard_match(b"fn_?", b"fn_"));
+        assert!(!wildcard_match(b"fn_?", b"fn_12"));
+        assert!(wildcard_match(b"?*", b"x"));
+        assert!(!wildcard_match(b"?*", b""));
+        assert!(wildcard_match(b"*?", b"\xff"));
+    }
+}
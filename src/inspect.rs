@@ -0,0 +1,99 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{ensure, Context as _};
+use clap::Parser;
+
+use crate::{disasm::decode_with_hex_replacement, stcm2::{self, Parameter, StringType}};
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(from_global)]
+    encoding: Option<super::Encoding>,
+    file: PathBuf,
+    #[arg(value_parser = crate::asm::parse_hex, help = "file offset of the parameter, in hex")]
+    offset: u32
+}
+
+#[derive(Parser)]
+pub struct ExplainArgs {
+    #[arg(value_parser = parse_triple, help = "the parameter's three words in hex, comma-separated (e.g. 0x1234,0x40000000,0x40000000)")]
+    words: [u32; 3]
+}
+
+fn parse_triple(s: &str) -> anyhow::Result<[u32; 3]> {
+    let words = s.split(',').map(|w| crate::asm::parse_hex(w.trim()).with_context(|| format!("bad word {w}"))).collect::<anyhow::Result<Vec<_>>>()?;
+    words.try_into().map_err(|words: Vec<_>| anyhow::anyhow!("expected 3 words, got {}", words.len()))
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let file = fs::read(&args.file)?;
+    let stcm2 = stcm2::from_bytes(file.clone().into()).with_context(|| format!("failed to parse {}", args.file.display()))?;
+    let offset = args.offset;
+
+    let start = usize::try_from(offset)?;
+    let words = file.get(start..start + 12).with_context(|| format!("offset {offset:#x} is past the end of the file"))?;
+    let words: [u32; 3] = std::array::from_fn(|i| u32::from_le_bytes(words[4*i..4*i + 4].try_into().unwrap()));
+
+    // the action whose parameter list covers the offset, if any
+    let (&addr, act) = stcm2.actions.range(..=offset).next_back()
+        .with_context(|| format!("offset {offset:#x} is before the first action"))?;
+    let params_start = addr + 16;
+    let data_addr = params_start + u32::try_from(12*act.params.len())?;
+    ensure!(offset >= params_start && offset < data_addr && (offset - params_start).is_multiple_of(12),
+        "offset {offset:#x} is not the start of a parameter (nearest action is at {addr:#x} with {} params starting at {params_start:#x})", act.params.len());
+    let index = (offset - params_start) / 12;
+
+    let param = Parameter::parse(words, data_addr, u32::try_from(act.data.len())?, u32::try_from(stcm2.global_data.len())?)?;
+
+    let explanation = match param {
+        Parameter::ActionRef(target) => {
+            let label = match stcm2.actions.get(&target).and_then(|act| act.label()) {
+                Some(label) => String::from_utf8_lossy(label).into_owned(),
+                None => format!("local_{target:X}")
+            };
+            format!("ActionRef to {target:#x} (label: {label})")
+        },
+        Parameter::DataPointer(ptr) => {
+            let strings = act.data_strings(args.encoding.unwrap_or_default().get())?;
+            let value = match strings.iter().find(|&&(pos, _)| pos == ptr).map(|(_, s)| s) {
+                Some(StringType::String(s)) => format!("{:?}", decode_with_hex_replacement(args.encoding.unwrap_or_default().get(), s)),
+                Some(StringType::Type0U32(n)) => format!("={n}"),
+                Some(StringType::Type1U32(n)) => format!("@={n}"),
+                None => "not the start of a string".to_owned()
+            };
+            format!("DataPointer to data+{ptr:#x} at {:#x} ({value})", data_addr + ptr)
+        },
+        Parameter::GlobalDataPointer(ptr) => match stcm2.global_data_word(ptr / 4) {
+            Ok(word) if ptr.is_multiple_of(4) => format!("GlobalDataPointer to global_data+{ptr} (holding {word:#010X})"),
+            _ => format!("GlobalDataPointer to global_data+{ptr}")
+        },
+        Parameter::Value(v) => format!("Value {v:#x} ({})", v as i32)
+    };
+
+    println!("Parameter at {offset:#08x}: [{:#010X}, {:#010X}, {:#010X}] → {explanation}", words[0], words[1], words[2]);
+    println!("param {index} of action at {addr:#08x}");
+
+    Ok(())
+}
+
+// Without a file there's no action or global data to check against, so pointer-shaped
+// words get every reading that could apply
+pub fn explain(args: ExplainArgs) -> anyhow::Result<()> {
+    let words = args.words;
+    let explanation = match Parameter::parse(words, 0, 0, 0)? {
+        Parameter::ActionRef(target) => format!("ActionRef to the action at {target:#x}"),
+        Parameter::Value(v) => {
+            let mut readings = vec![format!("Value {v:#x} ({})", v as i32)];
+            readings.push(format!("DataPointer to file offset {v:#x}, if that's inside the action's data block"));
+            if let Some(ptr) = v.checked_sub(stcm2::GLOBAL_DATA_OFFSET as u32) {
+                readings.push(format!("GlobalDataPointer to global_data+{ptr}, if the global data is longer than that"));
+            }
+            readings.join("\n  or ")
+        },
+        Parameter::DataPointer(_) | Parameter::GlobalDataPointer(_) => unreachable!("no data to point into")
+    };
+
+    println!("[{:#010X}, {:#010X}, {:#010X}] → {explanation}", words[0], words[1], words[2]);
+
+    Ok(())
+}
@@ -1,28 +1,54 @@
 #![forbid(unsafe_code)]
 
-use std::{fs, iter, path::PathBuf};
+use std::{collections::HashMap, fmt, fs, iter, ops::RangeInclusive, path::{Path, PathBuf}, sync::atomic::{AtomicBool, Ordering}};
 
 use anyhow::{ensure, Context};
 use clap::{Parser, Subcommand, ValueEnum};
+use bimap::BiMap;
 use saphyr::{LoadableYamlNode, Yaml};
 
 mod disasm;
+mod annotate;
+mod archive;
 mod asm;
+mod check_encoding;
+mod convert;
+mod count_strings;
+mod decompile;
+mod diff;
+mod dump_mnemonics;
+mod exports;
+mod inspect;
+mod merge;
+mod optimize;
+mod rebase;
+mod rename;
+mod replace_strings;
+mod search_strings;
+mod split;
+mod strip;
+mod symbolize;
+mod trace;
+mod translate;
 mod stcm2;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
 enum Encoding {
+    #[default]
     #[value(name = "utf-8")]
     Utf8,
     #[value(name = "sjis")]
-    ShiftJis
+    ShiftJis,
+    #[value(name = "euc-jp")]
+    EucJp
 }
 
 impl Encoding {
     fn get(self) -> &'static encoding_rs::Encoding {
         match self {
             Encoding::Utf8 => encoding_rs::UTF_8,
-            Encoding::ShiftJis => encoding_rs::SHIFT_JIS
+            Encoding::ShiftJis => encoding_rs::SHIFT_JIS,
+            Encoding::EucJp => encoding_rs::EUC_JP
         }
     }
 }
@@ -30,47 +56,195 @@ impl Encoding {
 #[derive(Subcommand)]
 enum Command {
     Disasm(disasm::Args),
-    Asm(asm::Args)
+    Decompile(decompile::Args),
+    Asm(asm::Args),
+    ConvertEncoding(convert::Args),
+    Merge(merge::Args),
+    Strip(strip::Args),
+    RenameExport(rename::Args),
+    ApplyTranslation(translate::Args),
+    InspectParam(inspect::Args),
+    ExplainParam(inspect::ExplainArgs),
+    CheckEncoding(check_encoding::Args),
+    Pack(archive::PackArgs),
+    Unpack(archive::UnpackArgs),
+    Symbolize(symbolize::Args),
+    Exports(exports::Args),
+    Rebase(rebase::Args),
+    Optimize(optimize::Args),
+    DumpMnemonics(dump_mnemonics::Args),
+    Diff(diff::Args),
+    Trace(trace::Args),
+    Annotate(annotate::Args),
+    Split(split::Args),
+    SearchStrings(search_strings::Args),
+    ReplaceStrings(replace_strings::Args),
+    CountStrings(count_strings::Args)
 }
 
 #[derive(Parser)]
 struct Args {
-    #[arg(global = true, short = 'c', help = "config.yaml file")]
-    config: Option<PathBuf>,
-    #[arg(global = true, short = 'e', help = "text encoding", value_enum, default_value_t = Encoding::Utf8)]
-    encoding: Encoding,
+    #[arg(global = true, short = 'c', help = "config.yaml file (may be repeated; later files override earlier ones)")]
+    config: Vec<PathBuf>,
+    #[arg(global = true, short = 'e', help = "text encoding (default: utf-8, or what the assembly's encoding comment says)", value_enum)]
+    encoding: Option<Encoding>,
+    #[arg(global = true, long, help = "treat warnings as errors")]
+    abort_on_warning: bool,
     #[command(subcommand)]
     cmd: Command
 }
 
+static ABORT_ON_WARNING: AtomicBool = AtomicBool::new(false);
+
+// Print a warning, or fail with it under --abort-on-warning
+pub fn warn(msg: fmt::Arguments<'_>) -> anyhow::Result<()> {
+    ensure!(!ABORT_ON_WARNING.load(Ordering::Relaxed), "{msg}");
+    eprintln!("warning: {msg}");
+    Ok(())
+}
+
+pub fn load_config(path: &Path) -> anyhow::Result<Yaml<'static>> {
+    let conf = fs::read_to_string(path)?;
+    let mut docs = Yaml::load_from_str(&conf)?;
+    ensure!(docs.len() == 1);
+    Ok(docs.pop().unwrap())
+}
+
+pub fn parse_mnemonics<'a>(conf: &'a Yaml<'static>) -> anyhow::Result<BiMap<&'a str, u32>> {
+    let Some(mnemonics) = conf.as_mapping_get("mnemonics") else {
+        return Ok(BiMap::new())
+    };
+
+    mnemonics
+        .as_mapping().context("mnemonics is not a mapping")?.iter()
+        .map(|(k, v)| {
+            let name = k.as_str().with_context(|| format!("mnemonic {k:?} is not a str"))?;
+            let opcode = v.as_integer().with_context(|| format!("opcode {v:?} is not an int"))?;
+            let opcode = opcode.try_into().with_context(|| format!("opcode {opcode:X} out of range"))?;
+            Ok((name, opcode))
+        })
+        .collect()
+}
+
+// later config files override earlier ones
+pub fn config_get<'a>(confs: &'a [Yaml<'static>], key: &str) -> Option<&'a Yaml<'static>> {
+    confs.iter().rev().find_map(|conf| conf.as_mapping_get(key))
+}
+
+pub fn parse_opcode_list(confs: &[Yaml<'static>], key: &str) -> anyhow::Result<Option<Vec<u32>>> {
+    let Some(list) = config_get(confs, key) else {
+        return Ok(None)
+    };
+
+    list
+        .as_sequence().with_context(|| format!("{key} is not a sequence"))?.iter()
+        .map(|v| {
+            let opcode = v.as_integer().with_context(|| format!("opcode {v:?} is not an int"))?;
+            opcode.try_into().with_context(|| format!("opcode {opcode:X} out of range"))
+        })
+        .collect::<anyhow::Result<_>>()
+        .map(Some)
+}
+
+// config.yaml's allowed_opcodes: [[lo, hi], ...], inclusive
+pub fn allowed_opcodes(confs: &[Yaml<'static>]) -> anyhow::Result<Option<Vec<RangeInclusive<u32>>>> {
+    let Some(list) = config_get(confs, "allowed_opcodes") else {
+        return Ok(None)
+    };
+
+    list
+        .as_sequence().context("allowed_opcodes is not a sequence")?.iter()
+        .map(|range| {
+            let bound = |i: usize| -> anyhow::Result<u32> {
+                let v = range.as_sequence().filter(|r| r.len() == 2).and_then(|r| r[i].as_integer())
+                    .with_context(|| format!("allowed_opcodes entry {range:?} is not a [lo, hi] pair"))?;
+                v.try_into().with_context(|| format!("opcode {v:X} out of range"))
+            };
+            Ok(bound(0)?..=bound(1)?)
+        })
+        .collect::<anyhow::Result<_>>()
+        .map(Some)
+}
+
+// config.yaml's opcode_signatures: { mnemonic: nparams }, keyed here by opcode
+pub fn opcode_signatures(mnemonics: &BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<HashMap<u32, usize>> {
+    let Some(signatures) = config_get(confs, "opcode_signatures") else {
+        return Ok(HashMap::new())
+    };
+
+    signatures
+        .as_mapping().context("opcode_signatures is not a mapping")?.iter()
+        .map(|(k, v)| {
+            let name = k.as_str().with_context(|| format!("mnemonic {k:?} is not a str"))?;
+            let opcode = *mnemonics.get_by_left(name).with_context(|| format!("opcode_signatures names unknown mnemonic {name}"))?;
+            let nparams = v.as_integer().with_context(|| format!("param count {v:?} is not an int"))?;
+            Ok((opcode, nparams.try_into().with_context(|| format!("param count {nparams} out of range"))?))
+        })
+        .collect()
+}
+
+// config.yaml's export_name_length, for variants with a wider export name field
+pub fn export_name_length(confs: &[Yaml<'static>]) -> anyhow::Result<Option<usize>> {
+    let Some(len) = config_get(confs, "export_name_length") else {
+        return Ok(None)
+    };
+    let len = len.as_integer().context("export_name_length is not an int")?;
+    ensure!(len > 0, "export_name_length {len} is not positive");
+    Ok(Some(len.try_into()?))
+}
+
+// opcodes that end a function: whatever the config calls "return", unless told otherwise
+pub fn function_terminators(mnemonics: &BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<Vec<u32>> {
+    Ok(match parse_opcode_list(confs, "chunk_terminators")? {
+        Some(ops) => ops,
+        None => vec![mnemonics.get_by_left("return").copied().unwrap_or(0)]
+    })
+}
+
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    ABORT_ON_WARNING.store(args.abort_on_warning, Ordering::Relaxed);
 
-    let conf = if let Some(conf) = args.config {
-        let conf = fs::read_to_string(conf)?;
-        let mut docs = Yaml::load_from_str(&conf)?;
-        ensure!(docs.len() == 1);
-        docs.pop()
-    } else {
-        None
-    };
+    let confs = args.config.iter().map(|p| load_config(p)).collect::<anyhow::Result<Vec<_>>>()?;
 
-    let mnemonics = if let Some(ref conf) = conf && let Some(mnemonics) = conf.as_mapping_get("mnemonics") {
+    let mnemonics = if confs.iter().any(|conf| conf.as_mapping_get("mnemonics").is_some()) {
+        // merge in order; BiMap::insert drops any earlier entry sharing a name or an opcode
+        let mut mnemonics = BiMap::new();
+        for conf in &confs {
+            for (name, opcode) in parse_mnemonics(conf)? {
+                mnemonics.insert(name, opcode);
+            }
+        }
         mnemonics
-            .as_mapping().context("mnemonics is not a mapping")?.iter()
-            .map(|(k, v)| {
-                let name = k.as_str().with_context(|| format!("mnemonic {k:?} is not a str"))?;
-                let opcode = v.as_integer().with_context(|| format!("opcode {v:?} is not an int"))?;
-                let opcode = opcode.try_into().with_context(|| format!("opcode {opcode:X} out of range"))?;
-                Ok((name, opcode))
-            })
-            .collect::<anyhow::Result<_>>()?
     } else {
         iter::once(("return", 0u32)).collect()
     };
 
     match args.cmd {
-        Command::Disasm(args) => disasm::main(args, mnemonics),
-        Command::Asm(args) => asm::main(args, mnemonics)
+        Command::Disasm(args) => disasm::main(args, mnemonics, &confs),
+        Command::Decompile(args) => decompile::main(args, mnemonics, &confs),
+        Command::Asm(args) => asm::main(args, mnemonics, &confs),
+        Command::ConvertEncoding(args) => convert::main(args),
+        Command::Merge(args) => merge::main(args),
+        Command::Strip(args) => strip::main(args, mnemonics, &confs),
+        Command::RenameExport(args) => rename::main(args),
+        Command::ApplyTranslation(args) => translate::main(args),
+        Command::InspectParam(args) => inspect::main(args),
+        Command::ExplainParam(args) => inspect::explain(args),
+        Command::CheckEncoding(args) => check_encoding::main(args),
+        Command::Pack(args) => archive::pack(args, &confs),
+        Command::Unpack(args) => archive::unpack(args, &confs),
+        Command::Symbolize(args) => symbolize::main(args),
+        Command::Exports(args) => exports::main(args),
+        Command::Rebase(args) => rebase::main(args),
+        Command::Optimize(args) => optimize::main(args, mnemonics, &confs),
+        Command::DumpMnemonics(args) => dump_mnemonics::main(args, mnemonics, &confs),
+        Command::Diff(args) => diff::main(args),
+        Command::Trace(args) => trace::main(args, mnemonics, &confs),
+        Command::Annotate(args) => annotate::main(args),
+        Command::Split(args) => split::main(args, mnemonics, &confs),
+        Command::SearchStrings(args) => search_strings::main(args, mnemonics),
+        Command::ReplaceStrings(args) => replace_strings::main(args),
+        Command::CountStrings(args) => count_strings::main(args, mnemonics)
     }
 }
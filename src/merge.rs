@@ -0,0 +1,63 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use anyhow::{bail, ensure, Context as _};
+use bstr::BStr;
+use clap::Parser;
+
+use crate::stcm2::{self, Parameter};
+
+#[derive(Parser)]
+pub struct Args {
+    a: PathBuf,
+    b: PathBuf,
+    output: PathBuf
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let mut a = stcm2::from_bytes(fs::read(&args.a)?.into()).with_context(|| format!("failed to parse {}", args.a.display()))?;
+    let b = stcm2::from_bytes(fs::read(&args.b)?.into()).with_context(|| format!("failed to parse {}", args.b.display()))?;
+
+    ensure!(a.tag == b.tag, "tags differ: {:?} vs {:?}", BStr::new(&a.tag), BStr::new(&b.tag));
+    ensure!(a.variant == b.variant, "variants differ: {:?} vs {:?}", a.variant, b.variant);
+    ensure!(a.global_data == b.global_data, "global data differs");
+
+    // both files start their code at the same address, so b's actions are moved to follow a's
+    let a_end = match a.actions.last_key_value() {
+        Some((&addr, act)) => addr + u32::try_from(act.len())?,
+        None => 0
+    };
+    let b_start = b.actions.first_key_value().map_or(0, |(&addr, _)| addr);
+    let delta = a_end.saturating_sub(b_start);
+
+    let mut exports = a.actions.iter()
+        .filter_map(|(&addr, act)| Some((act.label()?.to_vec(), addr)))
+        .collect::<HashMap<_, _>>();
+
+    for (addr, mut act) in b.actions {
+        let addr = addr + delta;
+        if act.call {
+            act.opcode += delta;
+        }
+        for param in &mut act.params {
+            if let Parameter::ActionRef(ptr) = param {
+                *ptr += delta;
+            }
+        }
+
+        if let Some(label) = act.label() {
+            if let Some(prev) = exports.get(label) {
+                bail!("export {} is defined in both files (at {prev:06X} in {} and {:06X} in {})",
+                    BStr::new(label), args.a.display(), addr - delta, args.b.display());
+            }
+            exports.insert(label.to_vec(), addr);
+        }
+
+        let res = a.actions.insert(addr, act);
+        ensure!(res.is_none(), "actions overlap at {addr:06X}");
+    }
+
+    let out = stcm2::to_bytes(&a, a.variant.filler())?;
+    fs::write(args.output, out)?;
+
+    Ok(())
+}
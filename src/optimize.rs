@@ -0,0 +1,84 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use anyhow::Context as _;
+use bimap::BiMap;
+use clap::Parser;
+use saphyr::Yaml;
+
+use crate::stcm2::{self, Parameter, Stcm2};
+
+#[derive(Parser)]
+#[command(after_help = "There is no pass that shares identical data blobs (or strings) across actions: \
+    a data pointer can only point into its own action's data, so each action keeps its own copy.")]
+pub struct Args {
+    #[arg(from_global)]
+    encoding: Option<super::Encoding>,
+    #[arg(long, help = "remove actions unreachable from any export (same as strip)")]
+    pass_dead_code: bool,
+    #[arg(long, help = "let params of the same action that point to identical strings or integers share one copy")]
+    pass_dedup_strings: bool,
+    input: PathBuf,
+    output: PathBuf
+}
+
+// Every data record (strings and integers alike) is shared within its action. A data pointer can only
+// point into its own action's data, so nothing can be shared across actions
+fn dedup_strings(stcm2: &mut Stcm2, encoding: &'static encoding_rs::Encoding) -> anyhow::Result<usize> {
+    let mut saved = 0;
+    for (&addr, act) in &mut stcm2.actions {
+        let strings = act.data_strings(encoding).with_context(|| format!("action at {addr:06X}"))?;
+        let junk_end = strings.first().map_or(act.data.len(), |&(pos, _)| pos as usize);
+
+        let mut data = act.data[..junk_end].to_vec();
+        let mut seen = HashMap::new();
+        let mut moved = HashMap::new();
+        for (i, &(pos, _)) in strings.iter().enumerate() {
+            let end = strings.get(i + 1).map_or(act.data.len(), |&(next, _)| next as usize);
+            let record = &act.data[pos as usize..end];
+            let new_pos = *seen.entry(record).or_insert_with(|| {
+                let new_pos = data.len();
+                data.extend_from_slice(record);
+                new_pos
+            });
+            moved.insert(pos, u32::try_from(new_pos)?);
+        }
+
+        if data.len() == act.data.len() { continue }
+
+        for param in &mut act.params {
+            if let Parameter::DataPointer(ptr) = param {
+                *ptr = *moved.get(ptr).with_context(|| format!("action at {addr:06X}: param references non-string"))?;
+            }
+        }
+        saved += act.data.len() - data.len();
+        act.data = data.into();
+    }
+    Ok(saved)
+}
+
+pub fn main(args: Args, mnemonics: BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    let file = fs::read(&args.input)?;
+    let in_len = file.len();
+    let mut stcm2 = stcm2::from_bytes(file.into()).with_context(|| format!("failed to parse {}", args.input.display()))?;
+
+    // no pass flags means every pass
+    let all = !args.pass_dead_code && !args.pass_dedup_strings;
+
+    if all || args.pass_dead_code {
+        let (count, code_size) = (stcm2.action_count(), stcm2.code_size());
+        crate::strip::strip_unreachable(&mut stcm2, &super::function_terminators(&mnemonics, confs)?)?;
+        println!("dead code: removed {} of {count} actions ({code_size} -> {} bytes of code)",
+            count - stcm2.action_count(), stcm2.code_size());
+    }
+
+    if all || args.pass_dedup_strings {
+        let saved = dedup_strings(&mut stcm2, args.encoding.unwrap_or_default().get())?;
+        println!("dedup strings: saved {saved} bytes");
+    }
+
+    let out = stcm2::to_bytes(&stcm2, stcm2.variant.filler())?;
+    println!("{in_len} -> {} bytes", out.len());
+    fs::write(args.output, out)?;
+
+    Ok(())
+}
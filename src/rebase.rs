@@ -0,0 +1,51 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::Context as _;
+use clap::Parser;
+
+use crate::stcm2::{self, Parameter};
+
+#[derive(Parser)]
+pub struct Args {
+    input: PathBuf,
+    #[arg(value_parser = crate::asm::parse_hex, help = "address to relocate the code to, in hex")]
+    new_base: u32,
+    output: PathBuf
+}
+
+// Patch the input in place rather than going through to_bytes, which would lay everything
+// out again at file offsets. Only references inside the code move; the header and export
+// table still locate things within the file. The input has to parse, so its base is always 0
+// (a rebased file's references point outside it and can't be read back)
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let mut file = fs::read(&args.input)?;
+    let stcm2 = stcm2::from_bytes(file.clone().into()).with_context(|| format!("failed to parse {}", args.input.display()))?;
+    let delta = args.new_base;
+
+    let mut relocate = |pos: u32| -> anyhow::Result<()> {
+        let pos = usize::try_from(pos)?;
+        let word = file.get_mut(pos..pos + 4).context("reference past the end of the file")?;
+        let value = u32::from_le_bytes(word.try_into()?).wrapping_add(delta);
+        word.copy_from_slice(&value.to_le_bytes());
+        Ok(())
+    };
+
+    // action keys are file offsets: [call, opcode, nparams, length] then 12 bytes per param
+    for (&addr, act) in &stcm2.actions {
+        if act.call {
+            relocate(addr + 4)?;
+        }
+        for (i, &param) in (0u32..).zip(&act.params) {
+            let pos = addr + 16 + 12*i;
+            match param {
+                Parameter::ActionRef(_) => relocate(pos + 4)?,
+                Parameter::DataPointer(_) | Parameter::GlobalDataPointer(_) => relocate(pos)?,
+                Parameter::Value(_) => ()
+            }
+        }
+    }
+
+    fs::write(args.output, file)?;
+
+    Ok(())
+}
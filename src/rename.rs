@@ -0,0 +1,25 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::Context as _;
+use clap::Parser;
+
+use crate::stcm2;
+
+#[derive(Parser)]
+pub struct Args {
+    input: PathBuf,
+    old: String,
+    new: String,
+    output: PathBuf
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let mut stcm2 = stcm2::from_bytes(fs::read(&args.input)?.into()).with_context(|| format!("failed to parse {}", args.input.display()))?;
+
+    stcm2.rename_export(args.old.as_bytes(), args.new.as_bytes())?;
+
+    let out = stcm2::to_bytes(&stcm2, stcm2.variant.filler())?;
+    fs::write(args.output, out)?;
+
+    Ok(())
+}
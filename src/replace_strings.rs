@@ -0,0 +1,52 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::Context as _;
+use clap::Parser;
+use regex::Regex;
+
+use crate::{convert::map_strings, stcm2};
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(from_global)]
+    encoding: Option<super::Encoding>,
+    #[arg(long, value_enum, help = "text encoding to write replaced strings in (default: same as -e)")]
+    to: Option<super::Encoding>,
+    input: PathBuf,
+    pattern: Regex,
+    #[arg(help = "replacement for every match; $1 or ${name} insert capture groups")]
+    replacement: String,
+    output: PathBuf
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let file = fs::read(&args.input)?.into();
+    let mut stcm2 = stcm2::from_bytes(file).with_context(|| format!("failed to parse {}", args.input.display()))?;
+
+    let from = args.encoding.unwrap_or_default().get();
+    let to = args.to.map_or(from, |to| to.get());
+
+    let mut replaced_count = 0;
+    map_strings(&mut stcm2, from, |addr, pos, s| {
+        let Some(decoded) = from.decode_without_bom_handling_and_without_replacement(s) else {
+            return Ok(None)
+        };
+        if !args.pattern.is_match(&decoded) {
+            return Ok(None)
+        }
+
+        // the data is laid out again on write, so a longer string just gets more padding words
+        let replaced = args.pattern.replace_all(&decoded, &*args.replacement);
+        let (encoded, _, unmappable) = to.encode(&replaced);
+        if unmappable { crate::warn(format_args!("encountered unmappable character in replacement of string at {addr:06X}+{pos:X}"))?; }
+        replaced_count += 1;
+        Ok(Some(encoded.into_owned()))
+    })?;
+
+    println!("replaced in {replaced_count} strings");
+
+    let out = stcm2::to_bytes(&stcm2, stcm2.variant.filler())?;
+    fs::write(args.output, out)?;
+
+    Ok(())
+}
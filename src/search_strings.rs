@@ -0,0 +1,101 @@
+use std::{collections::BTreeMap, fs, io::{self, BufWriter, Write as _}, iter, path::PathBuf};
+
+use anyhow::Context as _;
+use bimap::BiMap;
+use clap::Parser;
+use regex::Regex;
+
+use crate::{disasm::{decode_with_hex_replacement, label_to_string, LabelEscapeStyle}, stcm2::{self, Action, Parameter, StringType}};
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(from_global)]
+    encoding: Option<super::Encoding>,
+    #[arg(long, value_name = "N", default_value_t = 0, help = "also show N actions before and after each matching one")]
+    context: usize,
+    file: PathBuf,
+    pattern: Regex
+}
+
+// One line of context, roughly as disasm would write the action
+fn describe(actions: &BTreeMap<u32, Action>, addr: u32, mnemonics: &BiMap<&str, u32>, encoding: &'static encoding_rs::Encoding) -> anyhow::Result<String> {
+    let act = &actions[&addr];
+    let op = if act.call {
+        match actions.get(&act.opcode).and_then(|target| target.label()) {
+            Some(label) => format!("call {}", label_to_string(label, LabelEscapeStyle::Asm)),
+            None => format!("call fn_{:X}", act.opcode)
+        }
+    } else if let Some(name) = mnemonics.get_by_right(&act.opcode) {
+        (*name).to_owned()
+    } else {
+        format!("raw {:X}", act.opcode)
+    };
+    let strings = act.data_strings(encoding)?.into_iter().collect::<BTreeMap<_, _>>();
+    let params = act.params.iter().map(|&p| match p {
+        Parameter::Value(v) => format!("{v:X}"),
+        Parameter::ActionRef(addr) => format!("[{addr:06X}]"),
+        Parameter::GlobalDataPointer(offset) => format!("[global_data+{offset}]"),
+        Parameter::DataPointer(pos) => match strings.get(&pos) {
+            Some(StringType::String(s)) => format!("{:?}", decode_with_hex_replacement(encoding, s)),
+            Some(StringType::Type0U32(n)) => format!("={n}"),
+            Some(StringType::Type1U32(n)) => format!("@={n}"),
+            None => format!("data+{pos:X}")
+        }
+    });
+    let label = match act.label() {
+        Some(label) => format!("{}: ", label_to_string(label, LabelEscapeStyle::Asm)),
+        None => String::new()
+    };
+    Ok(format!("{addr:06X}  {label}{}", iter::once(op).chain(params).collect::<Vec<_>>().join(", ")))
+}
+
+pub fn main(args: Args, mnemonics: BiMap<&str, u32>) -> anyhow::Result<()> {
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    let stcm2 = stcm2::from_bytes(fs::read(&args.file)?.into()).with_context(|| format!("failed to parse {}", args.file.display()))?;
+    let encoding = args.encoding.unwrap_or_default().get();
+    let addrs = stcm2.actions.keys().copied().collect::<Vec<_>>();
+
+    // keyed by the matching action's index, so context can be shown once per run of nearby matches
+    let mut lines = BTreeMap::<usize, Vec<String>>::new();
+    for (i, (&addr, act)) in stcm2.actions.iter().enumerate() {
+        let strings = act.data_strings(encoding).with_context(|| format!("action at {addr:06X}"))?.into_iter().collect::<BTreeMap<_, _>>();
+        for (n, &p) in act.params.iter().enumerate() {
+            let Parameter::DataPointer(pos) = p else { continue };
+            let Some(StringType::String(s)) = strings.get(&pos) else { continue };
+            let s = decode_with_hex_replacement(encoding, s);
+            if args.pattern.is_match(&s) {
+                lines.entry(i).or_default().push(format!("{addr:06X} param {n}: {s:?}"));
+            }
+        }
+    }
+
+    if args.context == 0 {
+        for line in lines.into_values().flatten() {
+            writeln!(stdout, "{line}")?;
+        }
+    } else {
+        let mut last = None;
+        for &i in lines.keys() {
+            let start = i.saturating_sub(args.context).max(last.map_or(0, |l: usize| l + 1));
+            let end = (i + args.context).min(addrs.len() - 1);
+            if last.is_some_and(|l| start > l + 1) {
+                writeln!(stdout, "--")?;
+            }
+            for (j, &addr) in addrs.iter().enumerate().take(end + 1).skip(start) {
+                if j != i && lines.contains_key(&j) { break }
+                let marker = if j == i { '>' } else { ' ' };
+                writeln!(stdout, "{marker} {}", describe(&stcm2.actions, addr, &mnemonics, encoding)?)?;
+                if j == i {
+                    for line in &lines[&i] {
+                        writeln!(stdout, "    {line}")?;
+                    }
+                }
+                last = Some(j);
+            }
+        }
+    }
+
+    stdout.flush()?;
+
+    Ok(())
+}
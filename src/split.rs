@@ -0,0 +1,48 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{ensure, Context as _};
+use bimap::BiMap;
+use clap::Parser;
+use saphyr::Yaml;
+
+use crate::stcm2;
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(long = "by-export-prefix", value_name = "PREFIX", required = true,
+        help = "write one file with the exports starting with PREFIX and everything they reach (may be repeated)")]
+    prefixes: Vec<String>,
+    input: PathBuf,
+    outdir: PathBuf
+}
+
+pub fn main(args: Args, mnemonics: BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    let stcm2 = stcm2::from_bytes(fs::read(&args.input)?.into()).with_context(|| format!("failed to parse {}", args.input.display()))?;
+    let terminators = super::function_terminators(&mnemonics, confs)?;
+    let stem = args.input.file_stem().context("input has no file name")?.to_string_lossy();
+    // the prefix becomes part of a file name, which has to stay inside outdir
+    for prefix in &args.prefixes {
+        ensure!(!prefix.contains(['/', '\\', '\0']) && !prefix.contains(".."),
+            "export prefix {prefix:?} can't be used in a file name (no path separators or ..)");
+    }
+    fs::create_dir_all(&args.outdir)?;
+
+    for prefix in &args.prefixes {
+        let roots = stcm2.actions.iter()
+            .filter(|(_, act)| act.label().is_some_and(|l| l.starts_with(prefix.as_bytes())))
+            .map(|(&addr, _)| addr)
+            .collect::<Vec<_>>();
+        ensure!(!roots.is_empty(), "no exports start with {prefix}");
+
+        // exports from other groups stay exported if this group reaches them
+        let reachable = crate::strip::reachable_from(&stcm2, roots, &terminators)?;
+        let mut group = stcm2.clone();
+        group.actions.retain(|addr, _| reachable.contains(addr));
+
+        let path = args.outdir.join(format!("{stem}_{prefix}.dat"));
+        fs::write(&path, stcm2::to_bytes(&group, group.variant.filler())?)?;
+        println!("{}: {} of {} actions", path.display(), group.action_count(), stcm2.action_count());
+    }
+
+    Ok(())
+}
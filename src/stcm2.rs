@@ -1,17 +1,23 @@
-use std::collections::BTreeMap;
+use std::{borrow::Cow, collections::{BTreeMap, HashMap}, sync::LazyLock};
 
 use anyhow::{anyhow, bail, ensure, Context as _};
-use bytes::{Buf as _, Bytes};
+use bstr::BStr;
+use bytes::{Buf as _, BufMut as _, Bytes};
+use clap::ValueEnum;
+use regex::{Captures, Regex};
 
 pub const STCM2_MAGIC: &[u8] = b"STCM2";
 pub const STCM2_TAG_LENGTH: usize = 32 - STCM2_MAGIC.len();
+pub const UNK_HEADER_LENGTH: usize = 32;
+pub const STCM2L_MAGIC: &[u8] = b"STCM2L\0";
 pub const GLOBAL_DATA_MAGIC: &[u8] = b"GLOBAL_DATA\0";
-pub const GLOBAL_DATA_OFFSET: usize = STCM2_MAGIC.len() + STCM2_TAG_LENGTH + 12*4 + GLOBAL_DATA_MAGIC.len();
+pub const GLOBAL_DATA_OFFSET: usize = STCM2_MAGIC.len() + STCM2_TAG_LENGTH + 4*4 + UNK_HEADER_LENGTH + GLOBAL_DATA_MAGIC.len();
 pub const CODE_START_MAGIC: &[u8] = b"CODE_START_\0";
 pub const EXPORT_DATA_MAGIC: &[u8] = b"EXPORT_DATA\0";
 pub const COLLECTION_LINK_MAGIC: &[u8] = b"COLLECTION_LINK\0";
+pub const EXPORT_NAME_LENGTH: usize = 32;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Parameter {
     ActionRef(u32),
     DataPointer(u32),
@@ -34,13 +40,19 @@ impl Parameter {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Action {
     pub export: Option<Bytes>,
     pub call: bool,
     pub opcode: u32,
     pub params: Vec<Parameter>,
-    pub data: Bytes
+    pub data: Bytes,
+    // zero bytes between the end of this action and the next one
+    pub padding: usize
+}
+
+pub fn hex_bytes(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02X}")).collect::<Vec<_>>().join(" ")
 }
 
 impl Action {
@@ -55,43 +67,385 @@ impl Action {
     //const OP_XOR: u32 = 0xffffff08;
     //const OP_OR: u32 = 0xffffff09;
 
-    pub fn label(&self, junk: bool) -> Option<&[u8]> {
+    // the export name up to its first null; whatever follows is never part of the name
+    pub fn label(&self) -> Option<&[u8]> {
+        let b = &self.export.as_ref()?[..];
+        let pos = b.iter().position(|&z| z == 0).unwrap_or(b.len());
+        Some(&b[..pos])
+    }
+
+    // the whole export field minus trailing nulls, so disasm -j can reproduce bytes hidden after the name
+    pub fn label_with_junk(&self) -> Option<&[u8]> {
         let mut b = &self.export.as_ref()?[..];
-        if junk {
-            while let [rst @ .., 0] = b {
-                b = rst;
-            }
-            Some(b)
-        } else {
-            let pos = b.iter().position(|&z| z == 0).unwrap_or(b.len());
-            Some(&b[..pos])
+        while let [rst @ .., 0] = b {
+            b = rst;
         }
+        Some(b)
+    }
+
+    pub fn is_return(&self, return_opcode: u32) -> bool {
+        !self.call && self.opcode == return_opcode
     }
 
     pub fn len(&self) -> usize {
         16 + 12*self.params.len() + self.data.len()
     }
+
+    // Addresses of the actions this one calls or refers to
+    pub fn references(&self) -> impl Iterator<Item = u32> + '_ {
+        self.call.then_some(self.opcode).into_iter().chain(self.params.iter().filter_map(|&p| match p {
+            Parameter::ActionRef(addr) => Some(addr),
+            _ => None
+        }))
+    }
+
+    // Offsets into this action's data that its params point to, kept apart from references
+    // since they're relative to the data rather than action addresses
+    pub fn data_references(&self) -> impl Iterator<Item = u32> + '_ {
+        self.params.iter().filter_map(|&p| match p {
+            Parameter::DataPointer(ptr) => Some(ptr),
+            _ => None
+        })
+    }
+
+    // Decode the strings that follow any leading junk in the data, tagged with their offset
+    pub fn data_strings(&self, encoding: &'static encoding_rs::Encoding) -> anyhow::Result<Vec<(u32, StringType)>> {
+        let mut data = self.data.clone();
+        let mut pos = 0;
+
+        let mut at_beginning = true;
+
+        let mut data_pos = BTreeMap::new();
+
+        // pos never reaches data.len(), so there is always at least one byte to try decoding
+        while pos < data.len() {
+            if let Ok((s, tail)) = decode_string(encoding, pos.try_into()?, data.clone()) {
+                let consumed = self.data.len() - data.len();
+                if pos != 0 {
+                    ensure!(at_beginning, "unexpected bytes between parsed strings in data section at offset {consumed:X}: {}", hex_bytes(&data[..pos]));
+                }
+                at_beginning = false;
+
+                let abs_pos = u32::try_from(consumed + pos)?;
+                let res = data_pos.insert(abs_pos, s);
+                ensure!(res.is_none(), "decoded two strings at data offset {abs_pos:X}");
+
+                data = tail;
+                pos = 0;
+                continue;
+            }
+
+            pos += 1;
+        }
+
+        if !data.is_empty() {
+            ensure!(at_beginning, "unexpected trailing bytes after parsed strings in data section at offset {:X}: {}",
+                self.data.len() - data.len(), hex_bytes(&data));
+        }
+
+        Ok(data_pos.into_iter().collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StringType {
+    String(Bytes),
+    Type0U32(u32),
+    Type1U32(u32)
+}
+
+impl StringType {
+    pub fn type_(&self) -> u32 {
+        match self {
+            Self::String(_) | Self::Type0U32(_) => 0,
+            Self::Type1U32(_) => 1
+        }
+    }
+}
+
+// Technically a 2-3 byte heuristic
+fn four_byte_heuristic(encoding: &'static encoding_rs::Encoding, mut v: Bytes) -> StringType {
+    assert_eq!(v.len(), 4);
+
+    let n = v[..].try_into().map(u32::from_le_bytes).unwrap();
+
+    if n > 0xFFFFFF {
+        return StringType::Type0U32(n)
+    }
+
+    let nzero = v.iter().rev().take_while(|&&n| n == 0).count();
+
+    v.truncate(v.len() - nzero);
+
+    if v.len() < 3 || v[..] == *b"op" {
+        return StringType::Type0U32(n)
+    }
+
+    let Some(s) = encoding.decode_without_bom_handling_and_without_replacement(&v) else {
+        return StringType::Type0U32(n)
+    };
+
+    if s.chars().any(char::is_control) {
+        return StringType::Type0U32(n)
+    }
+
+    StringType::String(v)
+}
+
+// A string in an action's data: a 16-byte header (type, length in words, 1, length in bytes)
+// followed by the payload
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StringBuffer {
+    type_: u32,
+    data: Bytes
+}
+
+impl StringBuffer {
+    pub fn new(type_: u32, data: &[u8]) -> Self {
+        Self { type_, data: Bytes::copy_from_slice(data) }
+    }
+
+    // Read one string off the front of buf, leaving buf at whatever follows it
+    pub fn parse(buf: &mut Bytes) -> anyhow::Result<Self> {
+        ensure!(buf.len() > 16, "not enough room for magic");
+
+        let mut str = buf.clone();
+        let type_ = str.get_u32_le();
+        ensure!(matches!(type_, 0 | 1), "string magic isn't 0 or 1");
+        let qlen = str.get_u32_le();
+        ensure!(str.get_u32_le() == 1, "string magic isn't 1");
+        let len = str.get_u32_le();
+        ensure!(len == qlen*4, "len and qlen are inconsistent: len = {len}, qlen = {qlen}");
+        let len = len.try_into()?;
+
+        ensure!(str.len() >= len, "not enough room for string data");
+
+        *buf = str.split_off(len);
+        Ok(Self { type_, data: str })
+    }
+
+    pub fn type_(&self) -> u32 {
+        self.type_
+    }
+
+    pub fn data(&self) -> &Bytes {
+        &self.data
+    }
+
+    pub fn write(&self, buffer: &mut Vec<u8>) -> anyhow::Result<()> {
+        ensure!(self.data.len().is_multiple_of(4), "must be divisible by 4");
+        buffer.put_u32_le(self.type_);
+        buffer.put_u32_le((self.data.len() / 4).try_into()?);
+        buffer.put_u32_le(1);
+        buffer.put_u32_le(self.data.len().try_into()?);
+        buffer.put_slice(&self.data);
+        Ok(())
+    }
 }
 
-#[derive(Clone, Debug)]
+pub fn decode_string(encoding: &'static encoding_rs::Encoding, addr: u32, mut tail: Bytes) -> anyhow::Result<(StringType, Bytes)> {
+    tail.advance(addr as usize);
+
+    let buf = StringBuffer::parse(&mut tail)?;
+    let type_ = buf.type_();
+    let mut str = buf.data().clone();
+
+    if type_ == 1 && let Ok(n) = str[..].try_into().map(u32::from_le_bytes) {
+        return Ok((StringType::Type1U32(n), tail))
+    }
+
+    if str.len() == 4 {
+        return Ok((four_byte_heuristic(encoding, str), tail))
+    }
+
+    ensure!(type_ == 0, "string type is 1, but is not a u32");
+
+    // clip zeros off end
+    let nzero = str.iter().rev().take_while(|&&n| n == 0).count();
+    ensure!(matches!(nzero, 1..=4), "string is not canonical");
+    str.truncate(str.len() - nzero);
+
+    Ok((StringType::String(str), tail))
+}
+
+pub fn encode_bytestring(type_: u32, inner: &[u8], buffer: &mut Vec<u8>) -> anyhow::Result<()> {
+    StringBuffer::new(type_, inner).write(buffer)
+}
+
+// Encode string bytes that are already in the target encoding
+pub fn encode_raw_string(inner: &[u8], buffer: &mut Vec<u8>) -> anyhow::Result<()> {
+    let mut padded = inner.to_vec();
+    padded.put_bytes(0, 4 - inner.len() % 4);
+    encode_bytestring(0, &padded, buffer)
+}
+
+// Unescape and encode a string's contents; also says whether anything was unmappable
+fn escaped_to_bytes(encoding: &'static encoding_rs::Encoding, inner: &str) -> (Vec<u8>, bool) {
+    fn unsub_wellformed(wf: &str) -> Cow<'_, str> {
+        // note: this is a str regex
+        static PLACEHOLDER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"\\(?:x([0-9a-f]{2})|(["\\]))"#).unwrap());
+        PLACEHOLDER.replace_all(wf, |capt: &Captures<'_>| {
+            match (capt.get(1), capt.get(2)) {
+                (Some(g), None) => {
+                    // evil miniscule heap allocation LOL
+                    Cow::Owned(char::from(u8::from_str_radix(
+                        g.as_str(),
+                        16
+                    ).unwrap()).to_string())
+                },
+                (None, Some(g)) => {
+                    Cow::Borrowed(&wf[g.range()])
+                },
+                _ => unreachable!()
+            }
+        })
+    }
+
+    fn push(encoding: &'static encoding_rs::Encoding, piece: &str, out: &mut Vec<u8>) -> bool {
+        let piece = unsub_wellformed(piece);
+        let (bytes, _, replaced) = encoding.encode(&piece);
+        out.extend_from_slice(&bytes);
+        replaced
+    }
+
+    static MALFORMED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\\X([0-9a-f]{2})").unwrap());
+
+    let mut pieces = Vec::new();
+    let mut replaced = false;
+    let mut idx = 0;
+    while idx < inner.len() {
+        match MALFORMED.captures_at(inner, idx) {
+            None => {
+                replaced |= push(encoding, &inner[idx..], &mut pieces);
+                break;
+            },
+            Some(malformed) => {
+                let whole = malformed.get(0).unwrap();
+                if idx != whole.start() {
+                    replaced |= push(encoding, &inner[idx..whole.start()], &mut pieces);
+                }
+                pieces.push(u8::from_str_radix(malformed.get(1).unwrap().as_str(), 16).unwrap());
+                idx = whole.end();
+            }
+        }
+    }
+    (pieces, replaced)
+}
+
+pub fn can_encode(encoding: &'static encoding_rs::Encoding, inner: &str) -> bool {
+    !escaped_to_bytes(encoding, inner).1
+}
+
+// Encode an escaped string, with its header
+pub fn encode_string(encoding: &'static encoding_rs::Encoding, inner: &str, buffer: &mut Vec<u8>) -> anyhow::Result<()> {
+    let (bytes, replaced) = escaped_to_bytes(encoding, inner);
+    if replaced { crate::warn(format_args!("encountered unmappable character"))?; }
+    encode_raw_string(&bytes, buffer)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Stcm2 {
     pub tag: Bytes,
+    pub variant: Variant,
+    // the file length stored after COLLECTION_LINK, if the file had one; to_bytes always writes the real length
+    pub recorded_file_len: Option<u32>,
+    // the 32 bytes before GLOBAL_DATA, meaning unknown; usually zero
+    pub unk_header: Bytes,
     pub global_data: Bytes,
+    // width of the export name field; EXPORT_NAME_LENGTH except in some variants
+    pub export_name_length: usize,
     pub actions: BTreeMap<u32, Action>
 }
 
-pub fn from_bytes(mut file: Bytes) -> anyhow::Result<Stcm2> {
+impl Stcm2 {
+    pub fn action_count(&self) -> usize {
+        self.actions.len()
+    }
+
+    // bytes between CODE_START_ and EXPORT_DATA once written out, padding included
+    pub fn code_size(&self) -> usize {
+        self.actions.values().map(|act| act.len() + act.padding).sum()
+    }
+
+    // global data as little-endian words; offset counts words, not bytes
+    pub fn global_data_word(&self, offset: u32) -> anyhow::Result<u32> {
+        let pos = usize::try_from(offset)? * 4;
+        let word = self.global_data.get(pos..pos + 4)
+            .with_context(|| format!("global data word {offset} is out of range ({} bytes of global data)", self.global_data.len()))?;
+        Ok(u32::from_le_bytes(word.try_into()?))
+    }
+
+    // nothing writes single words at the moment, but it belongs with global_data_word
+    #[allow(dead_code)]
+    pub fn set_global_data_word(&mut self, offset: u32, value: u32) -> anyhow::Result<()> {
+        self.global_data_word(offset)?;
+        let pos = usize::try_from(offset)? * 4;
+        let mut data = self.global_data.to_vec();
+        data[pos..pos + 4].copy_from_slice(&value.to_le_bytes());
+        self.global_data = data.into();
+        Ok(())
+    }
+
+    pub fn rename_export(&mut self, old: &[u8], new: &[u8]) -> anyhow::Result<()> {
+        let addr = self.actions.iter().find(|(_, act)| act.label() == Some(old)).map(|(&addr, _)| addr)
+            .with_context(|| format!("no export named {}", BStr::new(old)))?;
+        self.set_export(addr, new)
+    }
+
+    pub fn set_export(&mut self, addr: u32, name: &[u8]) -> anyhow::Result<()> {
+        let max = self.export_name_length;
+        ensure!(name.len() <= max, "export label {} is {} bytes long (max {max})", BStr::new(name), name.len());
+        ensure!(!name.is_empty() && !name.contains(&0), "export label {:?} is empty or contains a null", BStr::new(name));
+        ensure!(self.actions.iter().all(|(&other, act)| other == addr || act.label() != Some(name)),
+            "export {} already exists", BStr::new(name));
+
+        let act = self.actions.get_mut(&addr).with_context(|| format!("no action at {addr:06X}"))?;
+        act.export = Some(Bytes::copy_from_slice(name));
+        Ok(())
+    }
+}
+
+// Whether buf starts with a header for an action that fits inside it
+fn is_action_header(buf: &[u8]) -> bool {
+    let word = |i: usize| buf.get(4*i..4*i + 4).map(|w| u32::from_le_bytes(w.try_into().unwrap()));
+    let (Some(call), Some(nparams), Some(length)) = (word(0), word(2), word(3)) else {
+        return false
+    };
+    call <= 1 && 16 + 12*u64::from(nparams) <= u64::from(length) && usize::try_from(length).is_ok_and(|l| l <= buf.len())
+}
+
+struct Header {
+    tag: Bytes,
+    variant: Variant,
+    export_addr: usize,
+    export_len: u32,
+    unk_header: Bytes,
+    global_data: Bytes,
+    code_start: usize
+}
+
+fn parse_header(file: &Bytes) -> anyhow::Result<Header> {
     let start_addr = file.as_ptr();
     let get_pos = |file: &Bytes| file.as_ptr() as usize - start_addr as usize;
+    let mut file = file.clone();
 
-    ensure!(file.starts_with(STCM2_MAGIC));
-    file.advance(STCM2_MAGIC.len());
-    let tag = file.split_to(STCM2_TAG_LENGTH);
+    ensure!(file.starts_with(STCM2_MAGIC), "file does not start with STCM2 magic bytes; got [{}{}] instead",
+        hex_bytes(&file[..file.len().min(8)]), if file.len() > 8 { " ..." } else { "" });
+    // some STCM2L files spell the magic out as STCM2L\0, which leaves less room for the tag
+    let (tag, variant) = if file.starts_with(STCM2L_MAGIC) {
+        file.advance(STCM2L_MAGIC.len());
+        (file.split_to(32 - STCM2L_MAGIC.len()), Variant::Stcm2L)
+    } else {
+        file.advance(STCM2_MAGIC.len());
+        let tag = file.split_to(STCM2_TAG_LENGTH);
+        let variant = Variant::from_tag(&tag);
+        (tag, variant)
+    };
     let export_addr = file.get_u32_le();
     let export_len = file.get_u32_le();
     let _unk1 = file.get_u32_le();
     let _collection_addr = file.get_u32_le();
-    let _unk = file.split_to(32);
+    let unk_header = file.split_to(UNK_HEADER_LENGTH);
     ensure!(file.starts_with(GLOBAL_DATA_MAGIC));
     file.advance(GLOBAL_DATA_MAGIC.len());
     ensure!(get_pos(&file) == GLOBAL_DATA_OFFSET);
@@ -103,49 +457,413 @@ pub fn from_bytes(mut file: Bytes) -> anyhow::Result<Stcm2> {
     ensure!(file.starts_with(CODE_START_MAGIC));
     file.advance(CODE_START_MAGIC.len());
 
+    // a zeroed or corrupt header would otherwise underflow the code section bound
+    let export_addr = usize::try_from(export_addr)?;
+    let file_len = get_pos(&file) + file.len();
+    ensure!(export_addr >= get_pos(&file) + EXPORT_DATA_MAGIC.len() && export_addr <= file_len,
+        "export_addr {export_addr:#x} is outside the file (code starts at {:#x}, file is {file_len:#x} bytes)", get_pos(&file));
+
+    Ok(Header { tag, variant, export_addr, export_len, unk_header, global_data, code_start: get_pos(&file) })
+}
+
+// Parse the actions laid out back to back from start up to end. Padding detection and length checks
+// look as far as code_end, so a range that stops early doesn't mistake a truncated header for padding
+fn parse_actions(file: &Bytes, start: usize, end: usize, code_end: usize, global_len: usize) -> anyhow::Result<BTreeMap<u32, Action>> {
+    let mut code = file.slice(start..code_end);
+    let get_pos = |code: &Bytes| code_end - code.len();
+
     let mut actions = BTreeMap::new();
 
-    while get_pos(&file) < usize::try_from(export_addr)? - EXPORT_DATA_MAGIC.len() {
-	    let addr = get_pos(&file).try_into()?;
-		
-        let global_call = file.get_u32_le();
-        let opcode = file.get_u32_le();
-        let nparams = file.get_u32_le();
-        let length = file.get_u32_le();
+    while get_pos(&code) < end {
+        let addr: u32 = get_pos(&code).try_into()?;
+        let remaining = code.len();
+        ensure!(remaining >= 16, "action at {addr:#x}: header runs past the end of the code section");
+
+        let global_call = code.get_u32_le();
+        let opcode = code.get_u32_le();
+        let nparams = code.get_u32_le();
+        let length = code.get_u32_le();
+
+        let min_length = 16 + 12*u64::from(nparams);
+        ensure!(u64::from(length) >= min_length, "action at {addr:#x}: length {length} < minimum {min_length}");
+        ensure!(usize::try_from(length).is_ok_and(|l| l <= remaining),
+            "action at {addr:#x}: length {length} runs past the end of the code section");
+        let ndata = length - 16 - 12*nparams;
+        ensure!(ndata.is_multiple_of(4), "action at {addr:#x}: data length {ndata} is not 4-byte aligned");
 
         let call = match global_call {
             0 => false,
             1 => true,
-            v => bail!("global_call = {v:08X}")
+            v => bail!("action at {addr:#x}: global_call = {v:#010x}")
         };
         let mut params = Vec::with_capacity(nparams.try_into()?);
-        for _ in 0..nparams {
-            let buffer = [file.get_u32_le(), file.get_u32_le(), file.get_u32_le()];
-            params.push(Parameter::parse(buffer, addr + 16 + 12*nparams, length - 16 - 12*nparams, global_len.try_into()?)?);
+        for i in 0..nparams {
+            let buffer = [code.get_u32_le(), code.get_u32_le(), code.get_u32_le()];
+            params.push(Parameter::parse(buffer, addr + 16 + 12*nparams, ndata, global_len.try_into()?)
+                .with_context(|| format!("action at {addr:#x}: param {i}"))?);
         }
 
-        let ndata = length - 16 - 12*nparams;
-        let data = file.split_to(ndata.try_into()?);
+        let data = code.split_to(ndata.try_into()?);
 
-        let res = actions.insert(addr, Action { export: None, call, opcode, params, data });
-        ensure!(res.is_none());
+        // zero words that can't start another action are alignment padding
+        let mut padding = 0;
+        while code.starts_with(&[0; 4]) && !is_action_header(&code) {
+            code.advance(4);
+            padding += 4;
+        }
+
+        let res = actions.insert(addr, Action { export: None, call, opcode, params, data, padding });
+        ensure!(res.is_none(), "action at {addr:#x}: parsed twice");
     }
+    ensure!(get_pos(&code) == end, "{end:#x} is not an action boundary (the last action ends at {:#x})", get_pos(&code));
+
+    Ok(actions)
+}
+
+// Parse only the actions in [start_addr, end_addr), which must be action boundaries inside the
+// code section. Exports aren't read, and refs may point outside the range
+pub fn parse_from_offset(file: Bytes, start_addr: u32, end_addr: u32) -> anyhow::Result<BTreeMap<u32, Action>> {
+    let header = parse_header(&file)?;
+    let (start, end) = (usize::try_from(start_addr)?, usize::try_from(end_addr)?);
+    let code_end = header.export_addr - EXPORT_DATA_MAGIC.len();
+    ensure!(header.code_start <= start && start <= end && end <= code_end,
+        "range {start:#x}..{end:#x} is not inside the code section ({:#x}..{code_end:#x})", header.code_start);
+    parse_actions(&file, start, end, code_end, header.global_data.len())
+}
+
+pub fn from_bytes(file: Bytes) -> anyhow::Result<Stcm2> {
+    from_bytes_with_export_name_length(file, None)
+}
+
+// export_name_length overrides the width guessed from the export table, e.g. from config.yaml
+pub fn from_bytes_with_export_name_length(file: Bytes, export_name_length: Option<usize>) -> anyhow::Result<Stcm2> {
+    let Header { tag, variant, export_addr, export_len, unk_header, global_data, code_start } = parse_header(&file)?;
+    let code_end = export_addr - EXPORT_DATA_MAGIC.len();
+    let mut actions = parse_actions(&file, code_start, code_end, code_end, global_data.len())?;
 
+    // a second CODE_START_ would point at a format variant this parser doesn't understand
+    let code = &file[code_start..code_end];
+    for (i, _) in code.windows(CODE_START_MAGIC.len()).enumerate().filter(|&(_, w)| w == CODE_START_MAGIC) {
+        crate::warn(format_args!("found another CODE_START_ magic at {:06X} inside the code section", code_start + i))?;
+    }
+
+    let mut file = file.slice(code_end..);
     ensure!(file.starts_with(EXPORT_DATA_MAGIC));
     file.advance(EXPORT_DATA_MAGIC.len());
 
+    // each export is a zero word, the name field and the address. Variants with a wider name field
+    // show it in where the collection link starts
+    let export_name_length = match (export_name_length, usize::try_from(export_len)?) {
+        (Some(len), _) => len,
+        (None, 0) => EXPORT_NAME_LENGTH,
+        (None, n) if file.get(n * (EXPORT_NAME_LENGTH + 8)..).is_some_and(|rest| rest.starts_with(COLLECTION_LINK_MAGIC)) => EXPORT_NAME_LENGTH,
+        (None, n) => match file.windows(COLLECTION_LINK_MAGIC.len()).position(|w| w == COLLECTION_LINK_MAGIC) {
+            Some(table_len) if table_len.is_multiple_of(n) && table_len / n > 8 => table_len / n - 8,
+            Some(_) => {
+                crate::warn(format_args!("can't tell how wide export names are, assuming {EXPORT_NAME_LENGTH} (set export_name_length in config.yaml)"))?;
+                EXPORT_NAME_LENGTH
+            },
+            None => EXPORT_NAME_LENGTH
+        }
+    };
+
     for _ in 0..export_len {
         ensure!(file.get_u32_le() == 0);
-        let export = file.split_to(32);
+        let export = file.split_to(export_name_length);
         let addr = file.get_u32_le();
         let act = actions.get_mut(&addr).context("export does not match known action")?;
         ensure!(act.export.is_none());
         act.export = Some(export);
     }
 
+    // the collection link is followed by a zero word and then the total file length
+    let recorded_file_len = if file.starts_with(COLLECTION_LINK_MAGIC) && file.len() >= COLLECTION_LINK_MAGIC.len() + 8 {
+        file.advance(COLLECTION_LINK_MAGIC.len());
+        let _unk = file.get_u32_le();
+        Some(file.get_u32_le())
+    } else {
+        None
+    };
+
+    // every call and action ref has to land on the start of an action
+    let mut bad_refs = Vec::new();
+    for (&addr, act) in &actions {
+        if act.call && !actions.contains_key(&act.opcode) {
+            bad_refs.push(format!("action at {addr:06X} calls {:06X}, which is not the start of an action", act.opcode));
+        }
+        for (i, &param) in act.params.iter().enumerate() {
+            if let Parameter::ActionRef(ptr) = param && !actions.contains_key(&ptr) {
+                bad_refs.push(format!("action at {addr:06X}, param {i}: ref to {ptr:06X}, which is not the start of an action"));
+            }
+        }
+    }
+    ensure!(bad_refs.is_empty(), "bad action references:\n{}", bad_refs.join("\n"));
+
     Ok(Stcm2 {
         tag,
+        variant,
+        recorded_file_len,
+        unk_header,
         global_data,
+        export_name_length,
         actions
     })
-}
\ No newline at end of file
+}
+
+// Both variants share the STCM2 magic; STCM2L files are the ones whose tag continues with L,
+// or whose magic is STCM2L\0.
+// The only known difference is the filler word used in parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Variant {
+    #[value(name = "stcm2")]
+    Stcm2,
+    #[value(name = "stcm2l")]
+    Stcm2L
+}
+
+impl Variant {
+    pub fn from_tag(tag: &[u8]) -> Self {
+        if tag.starts_with(b"L") { Self::Stcm2L } else { Self::Stcm2 }
+    }
+
+    pub fn filler(self) -> u32 {
+        match self {
+            Self::Stcm2 => 0xff000000,
+            Self::Stcm2L => 0x40000000
+        }
+    }
+}
+
+// The filler word a parsed file's parameters were written with. from_bytes accepts either filler,
+// so it has to be read back out of the file the actions came from.
+pub fn param_filler(stcm2: &Stcm2, file: &[u8]) -> Option<u32> {
+    let (&addr, _) = stcm2.actions.iter().find(|(_, act)| !act.params.is_empty())?;
+    // the third word of the first parameter is always filler
+    let pos = usize::try_from(addr).ok()? + 16 + 8;
+    Some(u32::from_le_bytes(file.get(pos..pos + 4)?.try_into().ok()?))
+}
+
+// Action addresses are only used as keys: every action is laid out again in key order,
+// and calls and ActionRefs are resolved against the keys.
+pub fn to_bytes(stcm2: &Stcm2, filler: u32) -> anyhow::Result<Vec<u8>> {
+    let mut out = Vec::new();
+
+    // an STCM2L tag that doesn't start with L can only be told apart by the STCM2L\0 magic
+    let magic = if stcm2.variant == Variant::Stcm2L && !stcm2.tag.starts_with(b"L") { STCM2L_MAGIC } else { STCM2_MAGIC };
+    let tag_length = 32 - magic.len();
+    ensure!(stcm2.tag.len() <= tag_length, "tag is longer than {tag_length} bytes");
+    out.put_slice(magic);
+    out.put_slice(&stcm2.tag);
+    out.put_bytes(0, tag_length - stcm2.tag.len());
+    let meta_idx = out.len();
+    out.put_bytes(0, 4*4); // filled in once the export and collection link addresses are known
+    ensure!(stcm2.unk_header.len() == UNK_HEADER_LENGTH, "unk_header is {} bytes (must be {UNK_HEADER_LENGTH})", stcm2.unk_header.len());
+    out.put_slice(&stcm2.unk_header);
+    out.put_slice(GLOBAL_DATA_MAGIC);
+    ensure!(out.len() == GLOBAL_DATA_OFFSET);
+    out.put_slice(&stcm2.global_data);
+    out.put_slice(CODE_START_MAGIC);
+
+    let code_base = out.len();
+
+    // temporary table to handle renaming pointers
+    let mut counter = code_base;
+    let renames = stcm2.actions.iter().map(|(&addr, act)| {
+        let pos = u32::try_from(counter)?;
+        counter += act.len() + act.padding;
+        Ok((addr, pos))
+    }).collect::<anyhow::Result<HashMap<_, _>>>()?;
+    let rename = |addr: u32| renames.get(&addr).copied().with_context(|| format!("reference to nonexistent action {addr:X}"));
+
+    let mut exports = Vec::new();
+
+    for (addr, act) in &stcm2.actions {
+        ensure!(out.len() == usize::try_from(renames[addr])?);
+
+        if let Some(ref export) = act.export {
+            exports.push((export, out.len()));
+        }
+
+        out.put_u32_le(act.call.into());
+        out.put_u32_le(if act.call { rename(act.opcode)? } else { act.opcode });
+        out.put_u32_le(u32::try_from(act.params.len())?);
+        out.put_u32_le(u32::try_from(act.len())?);
+
+        let data_base = out.len() + 12 * act.params.len();
+        for &param in &act.params {
+            match param {
+                Parameter::Value(val) => {
+                    out.put_u32_le(val);
+                    out.put_u32_le(filler);
+                    out.put_u32_le(filler);
+                },
+                Parameter::GlobalDataPointer(ptr) => {
+                    out.put_u32_le(u32::try_from(GLOBAL_DATA_OFFSET)? + ptr);
+                    out.put_u32_le(filler);
+                    out.put_u32_le(filler);
+                },
+                Parameter::DataPointer(ptr) => {
+                    out.put_u32_le(u32::try_from(data_base + usize::try_from(ptr)?)?);
+                    out.put_u32_le(filler);
+                    out.put_u32_le(filler);
+                },
+                Parameter::ActionRef(ptr) => {
+                    out.put_u32_le(0xffffff41);
+                    out.put_u32_le(rename(ptr)?);
+                    out.put_u32_le(filler);
+                }
+            }
+        }
+
+        out.put_slice(&act.data);
+        out.put_bytes(0, act.padding);
+    }
+
+    out.put_slice(EXPORT_DATA_MAGIC);
+    let export_addr = out.len();
+    {
+        let mut export_meta = &mut out[meta_idx..];
+        export_meta.put_u32_le(u32::try_from(export_addr)?);
+        export_meta.put_u32_le(u32::try_from(exports.len())?);
+    }
+    let export_name_length = stcm2.export_name_length;
+    for (name, addr) in exports {
+        ensure!(name.len() <= export_name_length, "export label is {} bytes long (max {export_name_length})", name.len());
+        out.put_u32_le(0);
+        out.put_slice(name);
+        out.put_bytes(0, export_name_length - name.len());
+        out.put_u32_le(u32::try_from(addr)?);
+    }
+
+    out.put_slice(COLLECTION_LINK_MAGIC);
+    let collection_link_len = 2;
+    let collection_link_addr = out.len();
+    {
+        let mut collection_meta = &mut out[meta_idx+8..];
+        collection_meta.put_u32_le(collection_link_len);
+        collection_meta.put_u32_le(collection_link_addr.try_into()?);
+    }
+    out.put_u32_le(0);
+    let write_file_len_here = out.len();
+    out.put_bytes(0, 60);
+    {
+        let len = out.len();
+        let mut write_file_len = &mut out[write_file_len_here..];
+        write_file_len.put_u32_le(len.try_into()?);
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action_exported_as(export: &'static [u8]) -> Action {
+        Action { export: Some(Bytes::from_static(export)), call: false, opcode: 0, params: Vec::new(), data: Bytes::new(), padding: 0 }
+    }
+
+    // encode_string then decode_string, giving back the raw string bytes
+    fn codec_round_trip(encoding: &'static encoding_rs::Encoding, escaped: &str) -> Bytes {
+        let mut buffer = Vec::new();
+        encode_string(encoding, escaped, &mut buffer).unwrap();
+        let (s, tail) = decode_string(encoding, 0, buffer.into()).unwrap();
+        assert!(tail.is_empty());
+        let StringType::String(s) = s else { panic!("{escaped:?} decoded as {s:?}") };
+        s
+    }
+
+    #[test]
+    fn shift_jis_text_round_trips() {
+        let text = "こんにちは、世界";
+        let bytes = codec_round_trip(encoding_rs::SHIFT_JIS, text);
+        assert_eq!(&bytes[..], &*encoding_rs::SHIFT_JIS.encode(text).0);
+        assert_eq!(encoding_rs::SHIFT_JIS.decode_without_bom_handling_and_without_replacement(&bytes).unwrap(), text);
+    }
+
+    #[test]
+    fn malformed_escape_is_a_raw_byte() {
+        // \XNN is how disasm writes a byte that doesn't decode; it must come back as that byte, not re-encoded
+        assert_eq!(&codec_round_trip(encoding_rs::SHIFT_JIS, r"ab\X82\Xffcd")[..], b"ab\x82\xffcd");
+        assert_eq!(&codec_round_trip(encoding_rs::UTF_8, r"\X82\X82\X82\X82\X82")[..], b"\x82\x82\x82\x82\x82");
+    }
+
+    #[test]
+    fn placeholder_escapes_are_characters() {
+        // \xNN is a character (encoded like any other), and \" and \\ are the quote and backslash
+        assert_eq!(&codec_round_trip(encoding_rs::UTF_8, r"say \x41\x42\x43")[..], b"say ABC");
+        assert_eq!(&codec_round_trip(encoding_rs::UTF_8, r#"\"quoted\" \\ back"#)[..], br#""quoted" \ back"#);
+        assert_eq!(&codec_round_trip(encoding_rs::UTF_8, r"caf\xe9!")[..], "café!".as_bytes());
+    }
+
+    fn file_exporting(export_name_length: usize, name: &'static [u8]) -> Stcm2 {
+        let mut act = action_exported_as(name);
+        act.params.push(Parameter::Value(1));
+        Stcm2 {
+            tag: Bytes::from_static(b"TEST"),
+            variant: Variant::Stcm2,
+            recorded_file_len: None,
+            unk_header: Bytes::from_static(&[0; UNK_HEADER_LENGTH]),
+            global_data: Bytes::from_static(&[0; 8]),
+            export_name_length,
+            actions: BTreeMap::from([(0, act)])
+        }
+    }
+
+    #[test]
+    fn export_name_length_is_read_from_the_export_table() {
+        for len in [EXPORT_NAME_LENGTH, 64] {
+            let bytes = to_bytes(&file_exporting(len, b"main"), Variant::Stcm2.filler()).unwrap();
+            let parsed = from_bytes(bytes.clone().into()).unwrap();
+            assert_eq!(parsed.export_name_length, len);
+            assert_eq!(parsed.actions.values().next().unwrap().label(), Some(&b"main"[..]));
+            assert_eq!(to_bytes(&parsed, Variant::Stcm2.filler()).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn param_filler_is_read_back_from_the_file() {
+        for variant in [Variant::Stcm2, Variant::Stcm2L] {
+            let bytes = to_bytes(&file_exporting(EXPORT_NAME_LENGTH, b"main"), variant.filler()).unwrap();
+            let parsed = from_bytes(bytes.clone().into()).unwrap();
+            assert_eq!(param_filler(&parsed, &bytes), Some(variant.filler()));
+        }
+    }
+
+    #[test]
+    fn global_data_words_are_little_endian() {
+        let mut stcm2 = file_exporting(EXPORT_NAME_LENGTH, b"main");
+        stcm2.set_global_data_word(1, 0x12345678).unwrap();
+        assert_eq!(&stcm2.global_data[..], [0, 0, 0, 0, 0x78, 0x56, 0x34, 0x12]);
+        assert_eq!(stcm2.global_data_word(1).unwrap(), 0x12345678);
+        assert!(stcm2.global_data_word(2).is_err());
+        assert!(stcm2.set_global_data_word(2, 0).is_err());
+    }
+
+    #[test]
+    fn parsed_files_compare_by_content() {
+        let bytes = to_bytes(&file_exporting(EXPORT_NAME_LENGTH, b"main"), Variant::Stcm2.filler()).unwrap();
+        let original = from_bytes(bytes.into()).unwrap();
+        let mut stcm2 = original.clone();
+        assert_eq!(stcm2, original);
+
+        let act = stcm2.actions.values_mut().next().unwrap();
+        act.opcode += 1;
+        assert_ne!(stcm2, original);
+        let act = stcm2.actions.values_mut().next().unwrap();
+        act.opcode -= 1;
+        assert_eq!(stcm2, original);
+    }
+
+    #[test]
+    fn label_stops_at_first_null() {
+        assert_eq!(action_exported_as(b"main\0junk\0\0\0").label(), Some(&b"main"[..]));
+        assert_eq!(action_exported_as(b"unterminated").label(), Some(&b"unterminated"[..]));
+    }
+
+    #[test]
+    fn label_with_junk_keeps_bytes_after_null() {
+        assert_eq!(action_exported_as(b"main\0junk\0\0\0").label_with_junk(), Some(&b"main\0junk"[..]));
+        assert_eq!(action_exported_as(b"main\0\0\0").label_with_junk(), Some(&b"main"[..]));
+    }
+}
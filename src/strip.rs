@@ -0,0 +1,57 @@
+use std::{collections::BTreeSet, fs, path::PathBuf};
+
+use anyhow::Context as _;
+use bimap::BiMap;
+use clap::Parser;
+use saphyr::Yaml;
+
+use crate::stcm2::{self, Stcm2};
+
+#[derive(Parser)]
+pub struct Args {
+    input: PathBuf,
+    output: PathBuf
+}
+
+// Everything reachable from roots, following calls, action refs and fallthrough
+pub fn reachable_from(stcm2: &Stcm2, roots: impl IntoIterator<Item = u32>, terminators: &[u32]) -> anyhow::Result<BTreeSet<u32>> {
+    let mut reachable = BTreeSet::new();
+    let mut pending = roots.into_iter().collect::<Vec<_>>();
+    while let Some(addr) = pending.pop() {
+        if !reachable.insert(addr) {
+            continue
+        }
+
+        let act = stcm2.actions.get(&addr).with_context(|| format!("reference to nonexistent action {addr:06X}"))?;
+        if !terminators.iter().any(|&op| act.is_return(op)) && let Some((&next, _)) = stcm2.actions.range(addr+1..).next() {
+            pending.push(next);
+        }
+        pending.extend(act.references());
+    }
+    Ok(reachable)
+}
+
+// Drop everything that isn't reachable from an export
+pub fn strip_unreachable(stcm2: &mut Stcm2, terminators: &[u32]) -> anyhow::Result<()> {
+    let exports = stcm2.actions.iter().filter(|(_, act)| act.export.is_some()).map(|(&addr, _)| addr);
+    let reachable = reachable_from(stcm2, exports, terminators)?;
+    stcm2.actions.retain(|addr, _| reachable.contains(addr));
+    Ok(())
+}
+
+pub fn main(args: Args, mnemonics: BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    let file = fs::read(&args.input)?;
+    let in_len = file.len();
+    let mut stcm2 = stcm2::from_bytes(file.into()).with_context(|| format!("failed to parse {}", args.input.display()))?;
+
+    let terminators = super::function_terminators(&mnemonics, confs)?;
+    let (count, code_size) = (stcm2.action_count(), stcm2.code_size());
+    strip_unreachable(&mut stcm2, &terminators)?;
+
+    let out = stcm2::to_bytes(&stcm2, stcm2.variant.filler())?;
+    println!("removed {} of {count} actions ({code_size} -> {} bytes of code), {in_len} -> {} bytes",
+        count - stcm2.action_count(), stcm2.code_size(), out.len());
+    fs::write(args.output, out)?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context as _};
+use bstr::BStr;
+use clap::Parser;
+
+use crate::stcm2;
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(long, help = "replace exports that already have a name")]
+    force: bool,
+    input: PathBuf,
+    #[arg(help = "symbol table with one \"0xADDR Name\" per line")]
+    symbols: PathBuf,
+    output: PathBuf
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let mut stcm2 = stcm2::from_bytes(fs::read(&args.input)?.into()).with_context(|| format!("failed to parse {}", args.input.display()))?;
+    let symbols = fs::read_to_string(&args.symbols)?;
+
+    for (i, line) in symbols.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') { continue }
+
+        let result = (|| {
+            let Some((addr, name)) = line.split_once(char::is_whitespace) else {
+                bail!("expected an address and a name")
+            };
+            let addr = crate::asm::parse_hex(addr).with_context(|| format!("bad address {addr}"))?;
+            let name = name.trim();
+
+            let act = stcm2.actions.get(&addr).with_context(|| format!("no action at {addr:06X}"))?;
+            if let Some(ref old) = act.export && !args.force {
+                if old != name.as_bytes() {
+                    crate::warn(format_args!("action at {addr:06X} is already exported as {}, keeping it (use --force to replace)", BStr::new(old)))?;
+                }
+                return Ok(())
+            }
+
+            stcm2.set_export(addr, name.as_bytes())
+        })();
+        result.with_context(|| format!("{}:{}: {line}", args.symbols.display(), i + 1))?;
+    }
+
+    let out = stcm2::to_bytes(&stcm2, stcm2.variant.filler())?;
+    fs::write(args.output, out)?;
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use std::{collections::BTreeMap, fs, io::{self, BufWriter, Write as _}, path::PathBuf};
+
+use anyhow::Context as _;
+use bimap::BiMap;
+use clap::Parser;
+use saphyr::Yaml;
+
+use crate::{disasm::{label_to_string, LabelEscapeStyle}, stcm2::{self, Action}};
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(long, help = "export to start from")]
+    entry: String,
+    #[arg(long, default_value_t = 3, help = "how many levels of calls to follow")]
+    depth: usize,
+    file: PathBuf
+}
+
+struct Tracer<'a, W> {
+    actions: &'a BTreeMap<u32, Action>,
+    terminators: Vec<u32>,
+    max_depth: usize,
+    out: W
+}
+
+impl<W: io::Write> Tracer<'_, W> {
+    fn name(&self, addr: u32) -> String {
+        match self.actions.get(&addr).and_then(|act| act.label()) {
+            Some(label) => label_to_string(label, LabelEscapeStyle::Asm).into_owned(),
+            None => format!("fn_{addr:X}")
+        }
+    }
+
+    // calls made from addr up to and including the first terminator, in order and without repeats
+    fn callees(&self, addr: u32) -> Vec<u32> {
+        let mut callees = Vec::new();
+        for (_, act) in self.actions.range(addr..) {
+            if act.call && !callees.contains(&act.opcode) {
+                callees.push(act.opcode);
+            }
+            if self.terminators.iter().any(|&op| act.is_return(op)) { break }
+        }
+        callees
+    }
+
+    fn walk(&mut self, addr: u32, prefix: &str, stack: &mut Vec<u32>) -> io::Result<()> {
+        if stack.len() > self.max_depth { return Ok(()) }
+
+        let callees = self.callees(addr);
+        for (i, &callee) in callees.iter().enumerate() {
+            let last = i + 1 == callees.len();
+            let (branch, indent) = if last { ("└── ", "    ") } else { ("├── ", "│   ") };
+            let note = if stack.contains(&callee) { " (recursive)" } else { "" };
+            writeln!(self.out, "{prefix}{branch}{}{note}", self.name(callee))?;
+            if note.is_empty() {
+                stack.push(callee);
+                self.walk(callee, &format!("{prefix}{indent}"), stack)?;
+                stack.pop();
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn main(args: Args, mnemonics: BiMap<&str, u32>, confs: &[Yaml<'static>]) -> anyhow::Result<()> {
+    let stcm2 = stcm2::from_bytes(fs::read(&args.file)?.into()).with_context(|| format!("failed to parse {}", args.file.display()))?;
+
+    let entry = stcm2.actions.iter().find(|(_, act)| act.label() == Some(args.entry.as_bytes())).map(|(&addr, _)| addr)
+        .with_context(|| format!("no export named {}", args.entry))?;
+
+    let mut tracer = Tracer {
+        actions: &stcm2.actions,
+        terminators: super::function_terminators(&mnemonics, confs)?,
+        max_depth: args.depth,
+        out: BufWriter::new(io::stdout().lock())
+    };
+    writeln!(tracer.out, "{}", tracer.name(entry))?;
+    tracer.walk(entry, "", &mut vec![entry])?;
+    tracer.out.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use anyhow::Context as _;
+use clap::Parser;
+
+use crate::{convert::map_strings, stcm2};
+
+#[derive(Parser)]
+pub struct Args {
+    #[arg(from_global)]
+    encoding: Option<super::Encoding>,
+    #[arg(long, value_enum, help = "text encoding to write translations in (default: same as -e)")]
+    to: Option<super::Encoding>,
+    input: PathBuf,
+    #[arg(help = "YAML mapping of original strings to their translations")]
+    translation: PathBuf,
+    output: PathBuf
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let translation = super::load_config(&args.translation)?;
+    let translation = translation
+        .as_mapping().context("translation file is not a mapping")?.iter()
+        .map(|(k, v)| Ok((
+            k.as_str().with_context(|| format!("original {k:?} is not a str"))?,
+            v.as_str().with_context(|| format!("translation {v:?} is not a str"))?
+        )))
+        .collect::<anyhow::Result<HashMap<_, _>>>()?;
+
+    let file = fs::read(&args.input)?.into();
+    let mut stcm2 = stcm2::from_bytes(file).with_context(|| format!("failed to parse {}", args.input.display()))?;
+
+    let from = args.encoding.unwrap_or_default().get();
+    let to = args.to.map_or(from, |to| to.get());
+
+    let mut replaced_count = 0;
+    map_strings(&mut stcm2, from, |addr, pos, s| {
+        let Some(decoded) = from.decode_without_bom_handling_and_without_replacement(s) else {
+            return Ok(None)
+        };
+        let Some(&translated) = translation.get(&*decoded) else {
+            return Ok(None)
+        };
+
+        let (encoded, _, replaced) = to.encode(translated);
+        if replaced { crate::warn(format_args!("encountered unmappable character in translation of string at {addr:06X}+{pos:X}"))?; }
+        replaced_count += 1;
+        Ok(Some(encoded.into_owned()))
+    })?;
+
+    println!("translated {replaced_count} strings");
+
+    let out = stcm2::to_bytes(&stcm2, stcm2.variant.filler())?;
+    fs::write(args.output, out)?;
+
+    Ok(())
+}
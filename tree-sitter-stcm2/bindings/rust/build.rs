@@ -0,0 +1,13 @@
+fn main() {
+    // src/ is what `tree-sitter generate` writes
+    let src_dir = std::path::Path::new("src");
+
+    let mut c_config = cc::Build::new();
+    c_config.std("c11").include(src_dir);
+
+    let parser_path = src_dir.join("parser.c");
+    c_config.file(&parser_path);
+    println!("cargo:rerun-if-changed={}", parser_path.display());
+
+    c_config.compile("tree-sitter-stcm2");
+}
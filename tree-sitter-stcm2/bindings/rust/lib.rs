@@ -0,0 +1,31 @@
+//! Tree-sitter grammar for the text format written by `stcm2-asm disasm` and read by `stcm2-asm asm`.
+//!
+//! ```
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&tree_sitter_stcm2::LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(".tag \"TEST\"\n.global_data AAAAAAAAAAA\n.code_start\nmain: return\n", None).unwrap();
+//! assert!(!tree.root_node().has_error());
+//! ```
+
+use tree_sitter_language::LanguageFn;
+
+extern "C" {
+    fn tree_sitter_stcm2() -> *const ();
+}
+
+/// The tree-sitter [`LanguageFn`] for this grammar
+pub const LANGUAGE: LanguageFn = unsafe { LanguageFn::from_raw(tree_sitter_stcm2) };
+
+/// The content of the [`node-types.json`] file for this grammar
+///
+/// [`node-types.json`]: https://tree-sitter.github.io/tree-sitter/using-parsers/6-static-node-types
+pub const NODE_TYPES: &str = include_str!("../../src/node-types.json");
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn can_load_grammar() {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&super::LANGUAGE.into()).expect("error loading the stcm2 grammar");
+    }
+}
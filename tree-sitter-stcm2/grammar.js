@@ -0,0 +1,92 @@
+// Tree-sitter grammar for the text format written by `stcm2-asm disasm` and read by `stcm2-asm asm`.
+// Generate the parser with `tree-sitter generate` from this directory and commit the regenerated
+// src/ along with any grammar change; `tree-sitter test` then runs test/corpus, and the Rust
+// bindings build from src/.
+
+// any printable ASCII except backslash, comma, colon and closing bracket; other bytes are written
+// as \xNN and a backslash as \\
+const LABEL = /([!-+\--9;-\[^-~]|\\x[0-9a-f]{2}|\\\\)+/;
+
+module.exports = grammar({
+  name: 'stcm2',
+
+  extras: _ => [/[ \t]/],
+
+  rules: {
+    source_file: $ => seq(
+      repeat($._newline),
+      optional(seq($.comment, $._newline)),
+      $.tag_directive, $._newline,
+      $.global_data_directive, $._newline,
+      optional(seq($.unk_header_directive, $._newline)),
+      optional(seq($.export_name_length_directive, $._newline)),
+      $.code_start_directive,
+      repeat(seq($._newline, optional($._line))),
+    ),
+
+    _newline: _ => /\r?\n/,
+
+    // the variant only follows the tag when the tag doesn't imply it (an STCM2L\0 magic)
+    tag_directive: $ => seq(optional($.address), '.tag', $.string, optional($.variant)),
+    variant: _ => choice('stcm2', 'stcm2l'),
+    global_data_directive: $ => seq(optional($.address), '.global_data', optional($.base64)),
+    unk_header_directive: $ => seq(optional($.address), '.unk_header', $.base64),
+    // only written when the export name field isn't 32 bytes wide
+    export_name_length_directive: $ => seq(optional($.address), '.export_name_length', $.decimal),
+    code_start_directive: $ => seq(optional($.address), '.code_start'),
+
+    _line: $ => choice($.include_directive, $.padding_directive, $.align_directive, $.action),
+
+    // asm splices in the named file's lines, relative to the including file
+    include_directive: $ => seq('#include', $.string),
+
+    padding_directive: $ => seq(optional($.address), '.padding', $.decimal),
+    align_directive: $ => seq(optional($.address), '.align', $.decimal),
+
+    // disasm -a prefixes every line with a six digit file offset
+    address: _ => token(prec(1, /[0-9A-F]{6}/)),
+
+    action: $ => seq(
+      optional($.address),
+      optional(field('label', $.label_definition)),
+      field('op', $._op),
+      repeat(seq(',', $._operand)),
+      optional($.junk),
+      optional($.comment),
+    ),
+
+    // the colon is part of the token; otherwise `return` at the start of a line would lex as a label
+    label_definition: _ => token(seq(LABEL, ':')),
+    label: _ => LABEL,
+
+    _op: $ => choice($.raw_op, $.call, $.mnemonic),
+    raw_op: $ => seq('raw', $.hex),
+    call: $ => seq('call', $.label),
+    mnemonic: _ => /[A-Za-z_][A-Za-z0-9_]*/,
+
+    _operand: $ => choice($.string, $.integer, $.action_ref, $.global_data_ref, $.call, $.value),
+
+    // one token, so spaces inside a string aren't skipped as extras. \XNN is a byte that didn't decode
+    string: _ => token(seq('"', repeat(choice(/[^"\\\n]+/, /\\["\\]/, /\\[xX][0-9a-f]{2}/)), '"')),
+    // =N is a type 0 integer and @=N a type 1 integer; older disassemblies wrote type 0 as @N
+    integer: $ => seq(choice('=', '@=', '@'), choice($.hex_integer, $.decimal)),
+    hex_integer: _ => /[0-9A-Fa-f]+h/,
+    action_ref: $ => seq('[', $.label, ']'),
+    global_data_ref: $ => seq('[', $.global_data_offset, ']'),
+    // outranks a label of the same length
+    global_data_offset: _ => token(prec(1, /global_data\+[0-9]+/)),
+    value: $ => choice($.hex, seq('-', $.decimal)),
+
+    hex: _ => /[0-9A-Fa-f]+/,
+    decimal: _ => /[0-9]+/,
+
+    // a trailing backslash continues the base64 on the next line (disasm --wrap-junk)
+    junk: $ => seq('!', $.base64, repeat(seq('\\', $._newline, $.base64))),
+    base64: _ => /[A-Za-z0-9+\/]+/,
+
+    // disasm writes `; [encoding: sjis]` before the tag and `; encoding: NAME` at the end of an
+    // action (--encoding-fallback), which asm reads back; other comments come from disasm
+    // options whose output can't be reassembled
+    comment: _ => /;[^\n]*/,
+  },
+});
@@ -0,0 +1,3645 @@
+/* Automatically @generated by tree-sitter */
+
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#define LANGUAGE_VERSION 15
+#define STATE_COUNT 155
+#define LARGE_STATE_COUNT 2
+#define SYMBOL_COUNT 59
+#define ALIAS_COUNT 0
+#define TOKEN_COUNT 34
+#define EXTERNAL_TOKEN_COUNT 0
+#define FIELD_COUNT 2
+#define MAX_ALIAS_SEQUENCE_LENGTH 13
+#define MAX_RESERVED_WORD_SET_SIZE 0
+#define PRODUCTION_ID_COUNT 5
+#define SUPERTYPE_COUNT 0
+
+enum ts_symbol_identifiers {
+  sym__newline = 1,
+  anon_sym_DOTtag = 2,
+  anon_sym_stcm2 = 3,
+  anon_sym_stcm2l = 4,
+  anon_sym_DOTglobal_data = 5,
+  anon_sym_DOTunk_header = 6,
+  anon_sym_DOTexport_name_length = 7,
+  anon_sym_DOTcode_start = 8,
+  anon_sym_POUNDinclude = 9,
+  anon_sym_DOTpadding = 10,
+  anon_sym_DOTalign = 11,
+  sym_address = 12,
+  anon_sym_COMMA = 13,
+  sym_label_definition = 14,
+  sym_label = 15,
+  anon_sym_raw = 16,
+  anon_sym_call = 17,
+  sym_mnemonic = 18,
+  sym_string = 19,
+  anon_sym_EQ = 20,
+  anon_sym_AT_EQ = 21,
+  anon_sym_AT = 22,
+  sym_hex_integer = 23,
+  anon_sym_LBRACK = 24,
+  anon_sym_RBRACK = 25,
+  sym_global_data_offset = 26,
+  anon_sym_DASH = 27,
+  sym_hex = 28,
+  sym_decimal = 29,
+  anon_sym_BANG = 30,
+  anon_sym_BSLASH = 31,
+  sym_base64 = 32,
+  sym_comment = 33,
+  sym_source_file = 34,
+  sym_tag_directive = 35,
+  sym_variant = 36,
+  sym_global_data_directive = 37,
+  sym_unk_header_directive = 38,
+  sym_export_name_length_directive = 39,
+  sym_code_start_directive = 40,
+  sym__line = 41,
+  sym_include_directive = 42,
+  sym_padding_directive = 43,
+  sym_align_directive = 44,
+  sym_action = 45,
+  sym__op = 46,
+  sym_raw_op = 47,
+  sym_call = 48,
+  sym__operand = 49,
+  sym_integer = 50,
+  sym_action_ref = 51,
+  sym_global_data_ref = 52,
+  sym_value = 53,
+  sym_junk = 54,
+  aux_sym_source_file_repeat1 = 55,
+  aux_sym_source_file_repeat2 = 56,
+  aux_sym_action_repeat1 = 57,
+  aux_sym_junk_repeat1 = 58,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [sym__newline] = "_newline",
+  [anon_sym_DOTtag] = ".tag",
+  [anon_sym_stcm2] = "stcm2",
+  [anon_sym_stcm2l] = "stcm2l",
+  [anon_sym_DOTglobal_data] = ".global_data",
+  [anon_sym_DOTunk_header] = ".unk_header",
+  [anon_sym_DOTexport_name_length] = ".export_name_length",
+  [anon_sym_DOTcode_start] = ".code_start",
+  [anon_sym_POUNDinclude] = "#include",
+  [anon_sym_DOTpadding] = ".padding",
+  [anon_sym_DOTalign] = ".align",
+  [sym_address] = "address",
+  [anon_sym_COMMA] = ",",
+  [sym_label_definition] = "label_definition",
+  [sym_label] = "label",
+  [anon_sym_raw] = "raw",
+  [anon_sym_call] = "call",
+  [sym_mnemonic] = "mnemonic",
+  [sym_string] = "string",
+  [anon_sym_EQ] = "=",
+  [anon_sym_AT_EQ] = "@=",
+  [anon_sym_AT] = "@",
+  [sym_hex_integer] = "hex_integer",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_RBRACK] = "]",
+  [sym_global_data_offset] = "global_data_offset",
+  [anon_sym_DASH] = "-",
+  [sym_hex] = "hex",
+  [sym_decimal] = "decimal",
+  [anon_sym_BANG] = "!",
+  [anon_sym_BSLASH] = "\\",
+  [sym_base64] = "base64",
+  [sym_comment] = "comment",
+  [sym_source_file] = "source_file",
+  [sym_tag_directive] = "tag_directive",
+  [sym_variant] = "variant",
+  [sym_global_data_directive] = "global_data_directive",
+  [sym_unk_header_directive] = "unk_header_directive",
+  [sym_export_name_length_directive] = "export_name_length_directive",
+  [sym_code_start_directive] = "code_start_directive",
+  [sym__line] = "_line",
+  [sym_include_directive] = "include_directive",
+  [sym_padding_directive] = "padding_directive",
+  [sym_align_directive] = "align_directive",
+  [sym_action] = "action",
+  [sym__op] = "_op",
+  [sym_raw_op] = "raw_op",
+  [sym_call] = "call",
+  [sym__operand] = "_operand",
+  [sym_integer] = "integer",
+  [sym_action_ref] = "action_ref",
+  [sym_global_data_ref] = "global_data_ref",
+  [sym_value] = "value",
+  [sym_junk] = "junk",
+  [aux_sym_source_file_repeat1] = "source_file_repeat1",
+  [aux_sym_source_file_repeat2] = "source_file_repeat2",
+  [aux_sym_action_repeat1] = "action_repeat1",
+  [aux_sym_junk_repeat1] = "junk_repeat1",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [sym__newline] = sym__newline,
+  [anon_sym_DOTtag] = anon_sym_DOTtag,
+  [anon_sym_stcm2] = anon_sym_stcm2,
+  [anon_sym_stcm2l] = anon_sym_stcm2l,
+  [anon_sym_DOTglobal_data] = anon_sym_DOTglobal_data,
+  [anon_sym_DOTunk_header] = anon_sym_DOTunk_header,
+  [anon_sym_DOTexport_name_length] = anon_sym_DOTexport_name_length,
+  [anon_sym_DOTcode_start] = anon_sym_DOTcode_start,
+  [anon_sym_POUNDinclude] = anon_sym_POUNDinclude,
+  [anon_sym_DOTpadding] = anon_sym_DOTpadding,
+  [anon_sym_DOTalign] = anon_sym_DOTalign,
+  [sym_address] = sym_address,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [sym_label_definition] = sym_label_definition,
+  [sym_label] = sym_label,
+  [anon_sym_raw] = anon_sym_raw,
+  [anon_sym_call] = anon_sym_call,
+  [sym_mnemonic] = sym_mnemonic,
+  [sym_string] = sym_string,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_AT_EQ] = anon_sym_AT_EQ,
+  [anon_sym_AT] = anon_sym_AT,
+  [sym_hex_integer] = sym_hex_integer,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [sym_global_data_offset] = sym_global_data_offset,
+  [anon_sym_DASH] = anon_sym_DASH,
+  [sym_hex] = sym_hex,
+  [sym_decimal] = sym_decimal,
+  [anon_sym_BANG] = anon_sym_BANG,
+  [anon_sym_BSLASH] = anon_sym_BSLASH,
+  [sym_base64] = sym_base64,
+  [sym_comment] = sym_comment,
+  [sym_source_file] = sym_source_file,
+  [sym_tag_directive] = sym_tag_directive,
+  [sym_variant] = sym_variant,
+  [sym_global_data_directive] = sym_global_data_directive,
+  [sym_unk_header_directive] = sym_unk_header_directive,
+  [sym_export_name_length_directive] = sym_export_name_length_directive,
+  [sym_code_start_directive] = sym_code_start_directive,
+  [sym__line] = sym__line,
+  [sym_include_directive] = sym_include_directive,
+  [sym_padding_directive] = sym_padding_directive,
+  [sym_align_directive] = sym_align_directive,
+  [sym_action] = sym_action,
+  [sym__op] = sym__op,
+  [sym_raw_op] = sym_raw_op,
+  [sym_call] = sym_call,
+  [sym__operand] = sym__operand,
+  [sym_integer] = sym_integer,
+  [sym_action_ref] = sym_action_ref,
+  [sym_global_data_ref] = sym_global_data_ref,
+  [sym_value] = sym_value,
+  [sym_junk] = sym_junk,
+  [aux_sym_source_file_repeat1] = aux_sym_source_file_repeat1,
+  [aux_sym_source_file_repeat2] = aux_sym_source_file_repeat2,
+  [aux_sym_action_repeat1] = aux_sym_action_repeat1,
+  [aux_sym_junk_repeat1] = aux_sym_junk_repeat1,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__newline] = {
+    .visible = false,
+    .named = true,
+  },
+  [anon_sym_DOTtag] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_stcm2] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_stcm2l] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOTglobal_data] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOTunk_header] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOTexport_name_length] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOTcode_start] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_POUNDinclude] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOTpadding] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOTalign] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_address] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_label_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_label] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_raw] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_call] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_mnemonic] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AT] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_hex_integer] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_global_data_offset] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_hex] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_decimal] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_BANG] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BSLASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_base64] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_source_file] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_tag_directive] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_variant] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_global_data_directive] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_unk_header_directive] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_export_name_length_directive] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_code_start_directive] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__line] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_include_directive] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_padding_directive] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_align_directive] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_action] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__op] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_raw_op] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_call] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__operand] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_integer] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_action_ref] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_global_data_ref] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_junk] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_source_file_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_source_file_repeat2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_action_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_junk_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+};
+
+enum ts_field_identifiers {
+  field_label = 1,
+  field_op = 2,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_label] = "label",
+  [field_op] = "op",
+};
+
+static const TSMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 1},
+  [2] = {.index = 1, .length = 1},
+  [3] = {.index = 2, .length = 2},
+  [4] = {.index = 4, .length = 2},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_op, 0},
+  [1] =
+    {field_op, 1},
+  [2] =
+    {field_label, 0},
+    {field_op, 1},
+  [4] =
+    {field_label, 1},
+    {field_op, 2},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 4,
+  [5] = 5,
+  [6] = 6,
+  [7] = 7,
+  [8] = 8,
+  [9] = 9,
+  [10] = 10,
+  [11] = 11,
+  [12] = 12,
+  [13] = 13,
+  [14] = 14,
+  [15] = 15,
+  [16] = 16,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 25,
+  [26] = 26,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
+  [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 35,
+  [36] = 36,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 40,
+  [41] = 41,
+  [42] = 42,
+  [43] = 43,
+  [44] = 44,
+  [45] = 45,
+  [46] = 46,
+  [47] = 47,
+  [48] = 48,
+  [49] = 49,
+  [50] = 50,
+  [51] = 51,
+  [52] = 52,
+  [53] = 53,
+  [54] = 54,
+  [55] = 55,
+  [56] = 56,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 60,
+  [61] = 61,
+  [62] = 62,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 83,
+  [84] = 84,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 93,
+  [94] = 94,
+  [95] = 95,
+  [96] = 96,
+  [97] = 97,
+  [98] = 98,
+  [99] = 99,
+  [100] = 100,
+  [101] = 101,
+  [102] = 102,
+  [103] = 103,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 109,
+  [110] = 110,
+  [111] = 111,
+  [112] = 112,
+  [113] = 113,
+  [114] = 114,
+  [115] = 115,
+  [116] = 116,
+  [117] = 117,
+  [118] = 118,
+  [119] = 119,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 133,
+  [134] = 134,
+  [135] = 135,
+  [136] = 136,
+  [137] = 137,
+  [138] = 138,
+  [139] = 139,
+  [140] = 140,
+  [141] = 141,
+  [142] = 142,
+  [143] = 143,
+  [144] = 144,
+  [145] = 145,
+  [146] = 146,
+  [147] = 147,
+  [148] = 148,
+  [149] = 149,
+  [150] = 150,
+  [151] = 151,
+  [152] = 152,
+  [153] = 153,
+  [154] = 154,
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(123);
+      ADVANCE_MAP(
+        '\n', 124,
+        '\r', 1,
+        '!', 225,
+        '"', 5,
+        '#', 74,
+        ',', 141,
+        '-', 209,
+        '.', 41,
+        ';', 228,
+        '=', 202,
+        '@', 204,
+        '[', 206,
+        '\\', 226,
+        ']', 207,
+        'c', 175,
+        'g', 191,
+        'r', 176,
+        's', 197,
+      );
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(0);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(190);
+      if (('A' <= lookahead && lookahead <= 'F')) ADVANCE(189);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(211);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('h' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 1:
+      if (lookahead == '\n') ADVANCE(124);
+      END_STATE();
+    case 2:
+      if (lookahead == '\n') ADVANCE(124);
+      if (lookahead == '\r') ADVANCE(1);
+      if (lookahead == '.') ADVANCE(51);
+      if (lookahead == ';') ADVANCE(228);
+      if (lookahead == 's') ADVANCE(101);
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(2);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(115);
+      END_STATE();
+    case 3:
+      if (lookahead == '\n') ADVANCE(124);
+      if (lookahead == '\r') ADVANCE(1);
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(3);
+      if (lookahead == '+' ||
+          ('/' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(227);
+      END_STATE();
+    case 4:
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '-') ADVANCE(209);
+      if (lookahead == '=') ADVANCE(202);
+      if (lookahead == '@') ADVANCE(204);
+      if (lookahead == '[') ADVANCE(206);
+      if (lookahead == 'c') ADVANCE(210);
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(4);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(222);
+      END_STATE();
+    case 5:
+      if (lookahead == '"') ADVANCE(201);
+      if (lookahead == '\\') ADVANCE(109);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(5);
+      END_STATE();
+    case 6:
+      if (lookahead == '.') ADVANCE(8);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'c') ADVANCE(163);
+      if (lookahead == 'r') ADVANCE(164);
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(6);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 7:
+      if (lookahead == '2') ADVANCE(127);
+      END_STATE();
+    case 8:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'a') ADVANCE(21);
+      if (lookahead == 'p') ADVANCE(9);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 9:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'a') ADVANCE(12);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 10:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'c') ADVANCE(20);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 11:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'd') ADVANCE(14);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 12:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'd') ADVANCE(13);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 13:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'd') ADVANCE(19);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 14:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'e') ADVANCE(135);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 15:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'g') ADVANCE(137);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 16:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'g') ADVANCE(23);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 17:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'i') ADVANCE(22);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 18:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'i') ADVANCE(16);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 19:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'i') ADVANCE(24);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 20:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'l') ADVANCE(25);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 21:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'l') ADVANCE(18);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 22:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'n') ADVANCE(10);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 23:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'n') ADVANCE(139);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 24:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'n') ADVANCE(15);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 25:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'u') ADVANCE(11);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 26:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(140);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 27:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(26);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 28:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(27);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 29:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(28);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 30:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(29);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 31:
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 32:
+      if (lookahead == '\\') ADVANCE(31);
+      if (lookahead == 'x') ADVANCE(120);
+      END_STATE();
+    case 33:
+      if (lookahead == '\\') ADVANCE(155);
+      if (lookahead == 'x') ADVANCE(121);
+      END_STATE();
+    case 34:
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == 'g') ADVANCE(150);
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(34);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 35:
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(35);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 36:
+      if (lookahead == '_') ADVANCE(73);
+      END_STATE();
+    case 37:
+      if (lookahead == '_') ADVANCE(99);
+      END_STATE();
+    case 38:
+      if (lookahead == '_') ADVANCE(59);
+      END_STATE();
+    case 39:
+      if (lookahead == '_') ADVANCE(91);
+      END_STATE();
+    case 40:
+      if (lookahead == '_') ADVANCE(83);
+      END_STATE();
+    case 41:
+      if (lookahead == 'a') ADVANCE(80);
+      if (lookahead == 'c') ADVANCE(92);
+      if (lookahead == 'e') ADVANCE(107);
+      if (lookahead == 'g') ADVANCE(81);
+      if (lookahead == 'p') ADVANCE(45);
+      if (lookahead == 't') ADVANCE(42);
+      if (lookahead == 'u') ADVANCE(87);
+      END_STATE();
+    case 42:
+      if (lookahead == 'a') ADVANCE(67);
+      END_STATE();
+    case 43:
+      if (lookahead == 'a') ADVANCE(85);
+      END_STATE();
+    case 44:
+      if (lookahead == 'a') ADVANCE(130);
+      END_STATE();
+    case 45:
+      if (lookahead == 'a') ADVANCE(56);
+      END_STATE();
+    case 46:
+      if (lookahead == 'a') ADVANCE(82);
+      END_STATE();
+    case 47:
+      if (lookahead == 'a') ADVANCE(98);
+      END_STATE();
+    case 48:
+      if (lookahead == 'a') ADVANCE(105);
+      END_STATE();
+    case 49:
+      if (lookahead == 'a') ADVANCE(58);
+      END_STATE();
+    case 50:
+      if (lookahead == 'b') ADVANCE(46);
+      END_STATE();
+    case 51:
+      if (lookahead == 'c') ADVANCE(92);
+      if (lookahead == 'e') ADVANCE(107);
+      if (lookahead == 'g') ADVANCE(81);
+      if (lookahead == 't') ADVANCE(42);
+      if (lookahead == 'u') ADVANCE(87);
+      END_STATE();
+    case 52:
+      if (lookahead == 'c') ADVANCE(84);
+      END_STATE();
+    case 53:
+      if (lookahead == 'c') ADVANCE(78);
+      END_STATE();
+    case 54:
+      if (lookahead == 'c') ADVANCE(180);
+      if (lookahead == 'r') ADVANCE(176);
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(54);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(224);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 55:
+      if (lookahead == 'd') ADVANCE(62);
+      END_STATE();
+    case 56:
+      if (lookahead == 'd') ADVANCE(60);
+      END_STATE();
+    case 57:
+      if (lookahead == 'd') ADVANCE(61);
+      END_STATE();
+    case 58:
+      if (lookahead == 'd') ADVANCE(63);
+      END_STATE();
+    case 59:
+      if (lookahead == 'd') ADVANCE(48);
+      END_STATE();
+    case 60:
+      if (lookahead == 'd') ADVANCE(76);
+      END_STATE();
+    case 61:
+      if (lookahead == 'e') ADVANCE(134);
+      END_STATE();
+    case 62:
+      if (lookahead == 'e') ADVANCE(37);
+      END_STATE();
+    case 63:
+      if (lookahead == 'e') ADVANCE(97);
+      END_STATE();
+    case 64:
+      if (lookahead == 'e') ADVANCE(40);
+      END_STATE();
+    case 65:
+      if (lookahead == 'e') ADVANCE(90);
+      END_STATE();
+    case 66:
+      if (lookahead == 'e') ADVANCE(49);
+      END_STATE();
+    case 67:
+      if (lookahead == 'g') ADVANCE(125);
+      END_STATE();
+    case 68:
+      if (lookahead == 'g') ADVANCE(136);
+      END_STATE();
+    case 69:
+      if (lookahead == 'g') ADVANCE(88);
+      END_STATE();
+    case 70:
+      if (lookahead == 'g') ADVANCE(102);
+      END_STATE();
+    case 71:
+      if (lookahead == 'h') ADVANCE(205);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(71);
+      END_STATE();
+    case 72:
+      if (lookahead == 'h') ADVANCE(132);
+      END_STATE();
+    case 73:
+      if (lookahead == 'h') ADVANCE(66);
+      END_STATE();
+    case 74:
+      if (lookahead == 'i') ADVANCE(86);
+      END_STATE();
+    case 75:
+      if (lookahead == 'i') ADVANCE(69);
+      END_STATE();
+    case 76:
+      if (lookahead == 'i') ADVANCE(89);
+      END_STATE();
+    case 77:
+      if (lookahead == 'k') ADVANCE(36);
+      END_STATE();
+    case 78:
+      if (lookahead == 'l') ADVANCE(106);
+      END_STATE();
+    case 79:
+      if (lookahead == 'l') ADVANCE(158);
+      END_STATE();
+    case 80:
+      if (lookahead == 'l') ADVANCE(75);
+      END_STATE();
+    case 81:
+      if (lookahead == 'l') ADVANCE(93);
+      END_STATE();
+    case 82:
+      if (lookahead == 'l') ADVANCE(38);
+      END_STATE();
+    case 83:
+      if (lookahead == 'l') ADVANCE(65);
+      END_STATE();
+    case 84:
+      if (lookahead == 'm') ADVANCE(7);
+      END_STATE();
+    case 85:
+      if (lookahead == 'm') ADVANCE(64);
+      END_STATE();
+    case 86:
+      if (lookahead == 'n') ADVANCE(53);
+      END_STATE();
+    case 87:
+      if (lookahead == 'n') ADVANCE(77);
+      END_STATE();
+    case 88:
+      if (lookahead == 'n') ADVANCE(138);
+      END_STATE();
+    case 89:
+      if (lookahead == 'n') ADVANCE(68);
+      END_STATE();
+    case 90:
+      if (lookahead == 'n') ADVANCE(70);
+      END_STATE();
+    case 91:
+      if (lookahead == 'n') ADVANCE(43);
+      END_STATE();
+    case 92:
+      if (lookahead == 'o') ADVANCE(55);
+      END_STATE();
+    case 93:
+      if (lookahead == 'o') ADVANCE(50);
+      END_STATE();
+    case 94:
+      if (lookahead == 'o') ADVANCE(96);
+      END_STATE();
+    case 95:
+      if (lookahead == 'p') ADVANCE(94);
+      END_STATE();
+    case 96:
+      if (lookahead == 'r') ADVANCE(103);
+      END_STATE();
+    case 97:
+      if (lookahead == 'r') ADVANCE(131);
+      END_STATE();
+    case 98:
+      if (lookahead == 'r') ADVANCE(100);
+      END_STATE();
+    case 99:
+      if (lookahead == 's') ADVANCE(104);
+      END_STATE();
+    case 100:
+      if (lookahead == 't') ADVANCE(133);
+      END_STATE();
+    case 101:
+      if (lookahead == 't') ADVANCE(52);
+      END_STATE();
+    case 102:
+      if (lookahead == 't') ADVANCE(72);
+      END_STATE();
+    case 103:
+      if (lookahead == 't') ADVANCE(39);
+      END_STATE();
+    case 104:
+      if (lookahead == 't') ADVANCE(47);
+      END_STATE();
+    case 105:
+      if (lookahead == 't') ADVANCE(44);
+      END_STATE();
+    case 106:
+      if (lookahead == 'u') ADVANCE(57);
+      END_STATE();
+    case 107:
+      if (lookahead == 'x') ADVANCE(95);
+      END_STATE();
+    case 108:
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(108);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(223);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(71);
+      END_STATE();
+    case 109:
+      if (lookahead == '"' ||
+          lookahead == '\\') ADVANCE(5);
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(119);
+      END_STATE();
+    case 110:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(208);
+      END_STATE();
+    case 111:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(140);
+      END_STATE();
+    case 112:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(111);
+      END_STATE();
+    case 113:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(112);
+      END_STATE();
+    case 114:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(113);
+      END_STATE();
+    case 115:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(114);
+      END_STATE();
+    case 116:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(5);
+      END_STATE();
+    case 117:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(31);
+      END_STATE();
+    case 118:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(155);
+      END_STATE();
+    case 119:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(116);
+      END_STATE();
+    case 120:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(117);
+      END_STATE();
+    case 121:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(118);
+      END_STATE();
+    case 122:
+      if (eof) ADVANCE(123);
+      if (lookahead == '\n') ADVANCE(124);
+      if (lookahead == '\r') ADVANCE(1);
+      if (lookahead == '#') ADVANCE(17);
+      if (lookahead == '.') ADVANCE(8);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'c') ADVANCE(163);
+      if (lookahead == 'r') ADVANCE(164);
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(122);
+      if (('A' <= lookahead && lookahead <= 'F')) ADVANCE(172);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(30);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '/') ||
+          (';' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 123:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 124:
+      ACCEPT_TOKEN(sym__newline);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(anon_sym_DOTtag);
+      END_STATE();
+    case 126:
+      ACCEPT_TOKEN(anon_sym_stcm2);
+      if (lookahead == 'l') ADVANCE(129);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(anon_sym_stcm2);
+      if (lookahead == 'l') ADVANCE(128);
+      END_STATE();
+    case 128:
+      ACCEPT_TOKEN(anon_sym_stcm2l);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(anon_sym_stcm2l);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(anon_sym_DOTglobal_data);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(anon_sym_DOTunk_header);
+      END_STATE();
+    case 132:
+      ACCEPT_TOKEN(anon_sym_DOTexport_name_length);
+      END_STATE();
+    case 133:
+      ACCEPT_TOKEN(anon_sym_DOTcode_start);
+      END_STATE();
+    case 134:
+      ACCEPT_TOKEN(anon_sym_POUNDinclude);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(anon_sym_POUNDinclude);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 136:
+      ACCEPT_TOKEN(anon_sym_DOTpadding);
+      END_STATE();
+    case 137:
+      ACCEPT_TOKEN(anon_sym_DOTpadding);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 138:
+      ACCEPT_TOKEN(anon_sym_DOTalign);
+      END_STATE();
+    case 139:
+      ACCEPT_TOKEN(anon_sym_DOTalign);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      END_STATE();
+    case 140:
+      ACCEPT_TOKEN(sym_address);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 142:
+      ACCEPT_TOKEN(sym_label_definition);
+      END_STATE();
+    case 143:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '+') ADVANCE(154);
+      if (lookahead == '\\') ADVANCE(33);
+      if (('!' <= lookahead && lookahead <= '*') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 144:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == '_') ADVANCE(149);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 145:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == 'a') ADVANCE(153);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == 'a') ADVANCE(143);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 147:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == 'a') ADVANCE(151);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == 'b') ADVANCE(147);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == 'd') ADVANCE(145);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == 'l') ADVANCE(152);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == 'l') ADVANCE(144);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == 'o') ADVANCE(148);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 153:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead == 't') ADVANCE(146);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 154:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(208);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '/') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(sym_label);
+      if (lookahead == '\\') ADVANCE(33);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          (';' <= lookahead && lookahead <= '[') ||
+          ('^' <= lookahead && lookahead <= '~')) ADVANCE(155);
+      END_STATE();
+    case 156:
+      ACCEPT_TOKEN(anon_sym_raw);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '/') ||
+          (';' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('0' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(anon_sym_raw);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 158:
+      ACCEPT_TOKEN(anon_sym_call);
+      END_STATE();
+    case 159:
+      ACCEPT_TOKEN(anon_sym_call);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '/') ||
+          (';' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('0' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 160:
+      ACCEPT_TOKEN(anon_sym_call);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 161:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == '+') ADVANCE(110);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 162:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == '2') ADVANCE(126);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 163:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'a') ADVANCE(166);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '/') ||
+          (';' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('0' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 164:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'a') ADVANCE(167);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '/') ||
+          (';' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('0' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 165:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'l') ADVANCE(159);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '/') ||
+          (';' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('0' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 166:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'l') ADVANCE(165);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '/') ||
+          (';' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('0' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'w') ADVANCE(156);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '/') ||
+          (';' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('0' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 168:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(140);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 169:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(168);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 170:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(169);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 171:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(170);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 172:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(171);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 173:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == ':') ADVANCE(142);
+      if (lookahead == '\\') ADVANCE(32);
+      if (('!' <= lookahead && lookahead <= '+') ||
+          ('-' <= lookahead && lookahead <= '/') ||
+          (';' <= lookahead && lookahead <= '@') ||
+          lookahead == '[' ||
+          lookahead == '^' ||
+          lookahead == '`' ||
+          ('{' <= lookahead && lookahead <= '~')) ADVANCE(31);
+      if (('0' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      END_STATE();
+    case 174:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == '_') ADVANCE(183);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 175:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'a') ADVANCE(184);
+      if (lookahead == 'h') ADVANCE(200);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('b' <= lookahead && lookahead <= 'f')) ADVANCE(190);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 176:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'a') ADVANCE(199);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 177:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'a') ADVANCE(161);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 178:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'a') ADVANCE(198);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 179:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'a') ADVANCE(193);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 180:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'a') ADVANCE(194);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 181:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'b') ADVANCE(179);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 182:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'c') ADVANCE(195);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 183:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'd') ADVANCE(178);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 184:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'h') ADVANCE(200);
+      if (lookahead == 'l') ADVANCE(192);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(190);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 185:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'h') ADVANCE(200);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(190);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(140);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 186:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'h') ADVANCE(200);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(190);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(185);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 187:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'h') ADVANCE(200);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(190);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(186);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 188:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'h') ADVANCE(200);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(190);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(187);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 189:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'h') ADVANCE(200);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(190);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(188);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 190:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'h') ADVANCE(200);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(190);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 191:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'l') ADVANCE(196);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 192:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'l') ADVANCE(160);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 193:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'l') ADVANCE(174);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 194:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'l') ADVANCE(192);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 195:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'm') ADVANCE(162);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 196:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'o') ADVANCE(181);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 197:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 't') ADVANCE(182);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 198:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 't') ADVANCE(177);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 199:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (lookahead == 'w') ADVANCE(157);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 200:
+      ACCEPT_TOKEN(sym_mnemonic);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(200);
+      END_STATE();
+    case 201:
+      ACCEPT_TOKEN(sym_string);
+      END_STATE();
+    case 202:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 203:
+      ACCEPT_TOKEN(anon_sym_AT_EQ);
+      END_STATE();
+    case 204:
+      ACCEPT_TOKEN(anon_sym_AT);
+      if (lookahead == '=') ADVANCE(203);
+      END_STATE();
+    case 205:
+      ACCEPT_TOKEN(sym_hex_integer);
+      END_STATE();
+    case 206:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 207:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 208:
+      ACCEPT_TOKEN(sym_global_data_offset);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(208);
+      END_STATE();
+    case 209:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      END_STATE();
+    case 210:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'a') ADVANCE(221);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('b' <= lookahead && lookahead <= 'f')) ADVANCE(222);
+      END_STATE();
+    case 211:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'h') ADVANCE(205);
+      if (('A' <= lookahead && lookahead <= 'F')) ADVANCE(219);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(220);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(215);
+      END_STATE();
+    case 212:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'h') ADVANCE(205);
+      if (('A' <= lookahead && lookahead <= 'F')) ADVANCE(140);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(220);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(140);
+      END_STATE();
+    case 213:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'h') ADVANCE(205);
+      if (('A' <= lookahead && lookahead <= 'F')) ADVANCE(216);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(220);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(212);
+      END_STATE();
+    case 214:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'h') ADVANCE(205);
+      if (('A' <= lookahead && lookahead <= 'F')) ADVANCE(217);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(220);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(213);
+      END_STATE();
+    case 215:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'h') ADVANCE(205);
+      if (('A' <= lookahead && lookahead <= 'F')) ADVANCE(218);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(220);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(214);
+      END_STATE();
+    case 216:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'h') ADVANCE(205);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(220);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(140);
+      END_STATE();
+    case 217:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'h') ADVANCE(205);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(220);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(216);
+      END_STATE();
+    case 218:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'h') ADVANCE(205);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(220);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(217);
+      END_STATE();
+    case 219:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'h') ADVANCE(205);
+      if (('a' <= lookahead && lookahead <= 'f')) ADVANCE(220);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F')) ADVANCE(218);
+      END_STATE();
+    case 220:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'h') ADVANCE(205);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(220);
+      END_STATE();
+    case 221:
+      ACCEPT_TOKEN(sym_hex);
+      if (lookahead == 'l') ADVANCE(79);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(222);
+      END_STATE();
+    case 222:
+      ACCEPT_TOKEN(sym_hex);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(222);
+      END_STATE();
+    case 223:
+      ACCEPT_TOKEN(sym_decimal);
+      if (lookahead == 'h') ADVANCE(205);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(223);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(71);
+      END_STATE();
+    case 224:
+      ACCEPT_TOKEN(sym_decimal);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(224);
+      END_STATE();
+    case 225:
+      ACCEPT_TOKEN(anon_sym_BANG);
+      END_STATE();
+    case 226:
+      ACCEPT_TOKEN(anon_sym_BSLASH);
+      END_STATE();
+    case 227:
+      ACCEPT_TOKEN(sym_base64);
+      if (lookahead == '+' ||
+          ('/' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(227);
+      END_STATE();
+    case 228:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(228);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 2},
+  [2] = {.lex_state = 122},
+  [3] = {.lex_state = 4},
+  [4] = {.lex_state = 6},
+  [5] = {.lex_state = 2},
+  [6] = {.lex_state = 2},
+  [7] = {.lex_state = 2},
+  [8] = {.lex_state = 0},
+  [9] = {.lex_state = 0},
+  [10] = {.lex_state = 2},
+  [11] = {.lex_state = 0},
+  [12] = {.lex_state = 0},
+  [13] = {.lex_state = 0},
+  [14] = {.lex_state = 0},
+  [15] = {.lex_state = 0},
+  [16] = {.lex_state = 0},
+  [17] = {.lex_state = 2},
+  [18] = {.lex_state = 54},
+  [19] = {.lex_state = 54},
+  [20] = {.lex_state = 0},
+  [21] = {.lex_state = 2},
+  [22] = {.lex_state = 0},
+  [23] = {.lex_state = 2},
+  [24] = {.lex_state = 2},
+  [25] = {.lex_state = 2},
+  [26] = {.lex_state = 0},
+  [27] = {.lex_state = 0},
+  [28] = {.lex_state = 0},
+  [29] = {.lex_state = 0},
+  [30] = {.lex_state = 2},
+  [31] = {.lex_state = 0},
+  [32] = {.lex_state = 0},
+  [33] = {.lex_state = 0},
+  [34] = {.lex_state = 0},
+  [35] = {.lex_state = 0},
+  [36] = {.lex_state = 0},
+  [37] = {.lex_state = 0},
+  [38] = {.lex_state = 2},
+  [39] = {.lex_state = 2},
+  [40] = {.lex_state = 2},
+  [41] = {.lex_state = 0},
+  [42] = {.lex_state = 2},
+  [43] = {.lex_state = 0},
+  [44] = {.lex_state = 0},
+  [45] = {.lex_state = 0},
+  [46] = {.lex_state = 2},
+  [47] = {.lex_state = 0},
+  [48] = {.lex_state = 0},
+  [49] = {.lex_state = 2},
+  [50] = {.lex_state = 0},
+  [51] = {.lex_state = 0},
+  [52] = {.lex_state = 0},
+  [53] = {.lex_state = 0},
+  [54] = {.lex_state = 2},
+  [55] = {.lex_state = 0},
+  [56] = {.lex_state = 0},
+  [57] = {.lex_state = 0},
+  [58] = {.lex_state = 0},
+  [59] = {.lex_state = 0},
+  [60] = {.lex_state = 0},
+  [61] = {.lex_state = 2},
+  [62] = {.lex_state = 2},
+  [63] = {.lex_state = 0},
+  [64] = {.lex_state = 0},
+  [65] = {.lex_state = 0},
+  [66] = {.lex_state = 0},
+  [67] = {.lex_state = 2},
+  [68] = {.lex_state = 2},
+  [69] = {.lex_state = 0},
+  [70] = {.lex_state = 2},
+  [71] = {.lex_state = 2},
+  [72] = {.lex_state = 0},
+  [73] = {.lex_state = 0},
+  [74] = {.lex_state = 0},
+  [75] = {.lex_state = 0},
+  [76] = {.lex_state = 0},
+  [77] = {.lex_state = 2},
+  [78] = {.lex_state = 0},
+  [79] = {.lex_state = 0},
+  [80] = {.lex_state = 0},
+  [81] = {.lex_state = 0},
+  [82] = {.lex_state = 0},
+  [83] = {.lex_state = 0},
+  [84] = {.lex_state = 0},
+  [85] = {.lex_state = 0},
+  [86] = {.lex_state = 0},
+  [87] = {.lex_state = 0},
+  [88] = {.lex_state = 3},
+  [89] = {.lex_state = 108},
+  [90] = {.lex_state = 34},
+  [91] = {.lex_state = 0},
+  [92] = {.lex_state = 0},
+  [93] = {.lex_state = 0},
+  [94] = {.lex_state = 0},
+  [95] = {.lex_state = 0},
+  [96] = {.lex_state = 0},
+  [97] = {.lex_state = 0},
+  [98] = {.lex_state = 0},
+  [99] = {.lex_state = 0},
+  [100] = {.lex_state = 0},
+  [101] = {.lex_state = 0},
+  [102] = {.lex_state = 3},
+  [103] = {.lex_state = 0},
+  [104] = {.lex_state = 0},
+  [105] = {.lex_state = 0},
+  [106] = {.lex_state = 0},
+  [107] = {.lex_state = 0},
+  [108] = {.lex_state = 0},
+  [109] = {.lex_state = 3},
+  [110] = {.lex_state = 54},
+  [111] = {.lex_state = 0},
+  [112] = {.lex_state = 0},
+  [113] = {.lex_state = 0},
+  [114] = {.lex_state = 54},
+  [115] = {.lex_state = 0},
+  [116] = {.lex_state = 0},
+  [117] = {.lex_state = 0},
+  [118] = {.lex_state = 0},
+  [119] = {.lex_state = 0},
+  [120] = {.lex_state = 0},
+  [121] = {.lex_state = 0},
+  [122] = {.lex_state = 3},
+  [123] = {.lex_state = 0},
+  [124] = {.lex_state = 0},
+  [125] = {.lex_state = 0},
+  [126] = {.lex_state = 0},
+  [127] = {.lex_state = 0},
+  [128] = {.lex_state = 0},
+  [129] = {.lex_state = 0},
+  [130] = {.lex_state = 0},
+  [131] = {.lex_state = 0},
+  [132] = {.lex_state = 54},
+  [133] = {.lex_state = 0},
+  [134] = {.lex_state = 54},
+  [135] = {.lex_state = 54},
+  [136] = {.lex_state = 0},
+  [137] = {.lex_state = 3},
+  [138] = {.lex_state = 0},
+  [139] = {.lex_state = 0},
+  [140] = {.lex_state = 4},
+  [141] = {.lex_state = 35},
+  [142] = {.lex_state = 54},
+  [143] = {.lex_state = 0},
+  [144] = {.lex_state = 54},
+  [145] = {.lex_state = 3},
+  [146] = {.lex_state = 0},
+  [147] = {.lex_state = 0},
+  [148] = {.lex_state = 0},
+  [149] = {.lex_state = 0},
+  [150] = {.lex_state = 0},
+  [151] = {.lex_state = 0},
+  [152] = {.lex_state = 0},
+  [153] = {.lex_state = 0},
+  [154] = {.lex_state = 0},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [STATE(0)] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [sym__newline] = ACTIONS(1),
+    [anon_sym_DOTtag] = ACTIONS(1),
+    [anon_sym_stcm2] = ACTIONS(1),
+    [anon_sym_stcm2l] = ACTIONS(1),
+    [anon_sym_DOTglobal_data] = ACTIONS(1),
+    [anon_sym_DOTunk_header] = ACTIONS(1),
+    [anon_sym_DOTexport_name_length] = ACTIONS(1),
+    [anon_sym_DOTcode_start] = ACTIONS(1),
+    [anon_sym_POUNDinclude] = ACTIONS(1),
+    [anon_sym_DOTpadding] = ACTIONS(1),
+    [anon_sym_DOTalign] = ACTIONS(1),
+    [sym_address] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_raw] = ACTIONS(1),
+    [anon_sym_call] = ACTIONS(1),
+    [sym_mnemonic] = ACTIONS(1),
+    [sym_string] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_AT_EQ] = ACTIONS(1),
+    [anon_sym_AT] = ACTIONS(1),
+    [sym_hex_integer] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [sym_global_data_offset] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [sym_hex] = ACTIONS(1),
+    [sym_decimal] = ACTIONS(1),
+    [anon_sym_BANG] = ACTIONS(1),
+    [anon_sym_BSLASH] = ACTIONS(1),
+    [sym_comment] = ACTIONS(1),
+  },
+  [STATE(1)] = {
+    [sym_source_file] = STATE(153),
+    [sym_tag_directive] = STATE(121),
+    [aux_sym_source_file_repeat1] = STATE(17),
+    [sym__newline] = ACTIONS(3),
+    [anon_sym_DOTtag] = ACTIONS(5),
+    [sym_address] = ACTIONS(7),
+    [sym_comment] = ACTIONS(9),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 11,
+    ACTIONS(13), 1,
+      anon_sym_POUNDinclude,
+    ACTIONS(15), 1,
+      anon_sym_DOTpadding,
+    ACTIONS(17), 1,
+      anon_sym_DOTalign,
+    ACTIONS(19), 1,
+      sym_address,
+    ACTIONS(21), 1,
+      sym_label_definition,
+    ACTIONS(23), 1,
+      anon_sym_raw,
+    ACTIONS(25), 1,
+      anon_sym_call,
+    ACTIONS(27), 1,
+      sym_mnemonic,
+    ACTIONS(11), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+    STATE(8), 3,
+      sym__op,
+      sym_raw_op,
+      sym_call,
+    STATE(99), 5,
+      sym__line,
+      sym_include_directive,
+      sym_padding_directive,
+      sym_align_directive,
+      sym_action,
+  [41] = 8,
+    ACTIONS(29), 1,
+      anon_sym_call,
+    ACTIONS(31), 1,
+      sym_string,
+    ACTIONS(35), 1,
+      anon_sym_AT,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(41), 1,
+      sym_hex,
+    ACTIONS(33), 2,
+      anon_sym_EQ,
+      anon_sym_AT_EQ,
+    STATE(28), 6,
+      sym_call,
+      sym__operand,
+      sym_integer,
+      sym_action_ref,
+      sym_global_data_ref,
+      sym_value,
+  [72] = 7,
+    ACTIONS(23), 1,
+      anon_sym_raw,
+    ACTIONS(25), 1,
+      anon_sym_call,
+    ACTIONS(43), 1,
+      anon_sym_DOTpadding,
+    ACTIONS(45), 1,
+      anon_sym_DOTalign,
+    ACTIONS(47), 1,
+      sym_label_definition,
+    ACTIONS(49), 1,
+      sym_mnemonic,
+    STATE(12), 3,
+      sym__op,
+      sym_raw_op,
+      sym_call,
+  [96] = 7,
+    ACTIONS(51), 1,
+      anon_sym_DOTunk_header,
+    ACTIONS(53), 1,
+      anon_sym_DOTexport_name_length,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(57), 1,
+      sym_address,
+    STATE(69), 1,
+      sym_code_start_directive,
+    STATE(148), 1,
+      sym_unk_header_directive,
+    STATE(149), 1,
+      sym_export_name_length_directive,
+  [118] = 7,
+    ACTIONS(51), 1,
+      anon_sym_DOTunk_header,
+    ACTIONS(53), 1,
+      anon_sym_DOTexport_name_length,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(57), 1,
+      sym_address,
+    STATE(51), 1,
+      sym_code_start_directive,
+    STATE(113), 1,
+      sym_unk_header_directive,
+    STATE(115), 1,
+      sym_export_name_length_directive,
+  [140] = 7,
+    ACTIONS(51), 1,
+      anon_sym_DOTunk_header,
+    ACTIONS(53), 1,
+      anon_sym_DOTexport_name_length,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(57), 1,
+      sym_address,
+    STATE(53), 1,
+      sym_code_start_directive,
+    STATE(118), 1,
+      sym_unk_header_directive,
+    STATE(119), 1,
+      sym_export_name_length_directive,
+  [162] = 6,
+    ACTIONS(61), 1,
+      anon_sym_COMMA,
+    ACTIONS(63), 1,
+      anon_sym_BANG,
+    ACTIONS(65), 1,
+      sym_comment,
+    STATE(13), 1,
+      aux_sym_action_repeat1,
+    STATE(43), 1,
+      sym_junk,
+    ACTIONS(59), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [182] = 6,
+    ACTIONS(61), 1,
+      anon_sym_COMMA,
+    ACTIONS(63), 1,
+      anon_sym_BANG,
+    ACTIONS(69), 1,
+      sym_comment,
+    STATE(20), 1,
+      aux_sym_action_repeat1,
+    STATE(59), 1,
+      sym_junk,
+    ACTIONS(67), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [202] = 7,
+    ACTIONS(51), 1,
+      anon_sym_DOTunk_header,
+    ACTIONS(53), 1,
+      anon_sym_DOTexport_name_length,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(57), 1,
+      sym_address,
+    STATE(44), 1,
+      sym_code_start_directive,
+    STATE(126), 1,
+      sym_unk_header_directive,
+    STATE(154), 1,
+      sym_export_name_length_directive,
+  [224] = 6,
+    ACTIONS(61), 1,
+      anon_sym_COMMA,
+    ACTIONS(63), 1,
+      anon_sym_BANG,
+    ACTIONS(73), 1,
+      sym_comment,
+    STATE(15), 1,
+      aux_sym_action_repeat1,
+    STATE(48), 1,
+      sym_junk,
+    ACTIONS(71), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [244] = 6,
+    ACTIONS(61), 1,
+      anon_sym_COMMA,
+    ACTIONS(63), 1,
+      anon_sym_BANG,
+    ACTIONS(77), 1,
+      sym_comment,
+    STATE(9), 1,
+      aux_sym_action_repeat1,
+    STATE(47), 1,
+      sym_junk,
+    ACTIONS(75), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [264] = 6,
+    ACTIONS(61), 1,
+      anon_sym_COMMA,
+    ACTIONS(63), 1,
+      anon_sym_BANG,
+    ACTIONS(81), 1,
+      sym_comment,
+    STATE(20), 1,
+      aux_sym_action_repeat1,
+    STATE(52), 1,
+      sym_junk,
+    ACTIONS(79), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [284] = 6,
+    ACTIONS(61), 1,
+      anon_sym_COMMA,
+    ACTIONS(63), 1,
+      anon_sym_BANG,
+    ACTIONS(85), 1,
+      sym_comment,
+    STATE(16), 1,
+      aux_sym_action_repeat1,
+    STATE(58), 1,
+      sym_junk,
+    ACTIONS(83), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [304] = 6,
+    ACTIONS(61), 1,
+      anon_sym_COMMA,
+    ACTIONS(63), 1,
+      anon_sym_BANG,
+    ACTIONS(89), 1,
+      sym_comment,
+    STATE(20), 1,
+      aux_sym_action_repeat1,
+    STATE(60), 1,
+      sym_junk,
+    ACTIONS(87), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [324] = 6,
+    ACTIONS(61), 1,
+      anon_sym_COMMA,
+    ACTIONS(63), 1,
+      anon_sym_BANG,
+    ACTIONS(93), 1,
+      sym_comment,
+    STATE(20), 1,
+      aux_sym_action_repeat1,
+    STATE(66), 1,
+      sym_junk,
+    ACTIONS(91), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [344] = 6,
+    ACTIONS(5), 1,
+      anon_sym_DOTtag,
+    ACTIONS(7), 1,
+      sym_address,
+    ACTIONS(95), 1,
+      sym__newline,
+    ACTIONS(97), 1,
+      sym_comment,
+    STATE(21), 1,
+      aux_sym_source_file_repeat1,
+    STATE(146), 1,
+      sym_tag_directive,
+  [363] = 4,
+    ACTIONS(23), 1,
+      anon_sym_raw,
+    ACTIONS(25), 1,
+      anon_sym_call,
+    ACTIONS(99), 1,
+      sym_mnemonic,
+    STATE(11), 3,
+      sym__op,
+      sym_raw_op,
+      sym_call,
+  [378] = 4,
+    ACTIONS(23), 1,
+      anon_sym_raw,
+    ACTIONS(25), 1,
+      anon_sym_call,
+    ACTIONS(101), 1,
+      sym_mnemonic,
+    STATE(14), 3,
+      sym__op,
+      sym_raw_op,
+      sym_call,
+  [393] = 3,
+    ACTIONS(105), 1,
+      anon_sym_COMMA,
+    STATE(20), 1,
+      aux_sym_action_repeat1,
+    ACTIONS(103), 4,
+      ts_builtin_sym_end,
+      sym__newline,
+      anon_sym_BANG,
+      sym_comment,
+  [406] = 3,
+    ACTIONS(108), 1,
+      sym__newline,
+    STATE(21), 1,
+      aux_sym_source_file_repeat1,
+    ACTIONS(111), 3,
+      anon_sym_DOTtag,
+      sym_address,
+      sym_comment,
+  [418] = 3,
+    ACTIONS(115), 1,
+      anon_sym_BSLASH,
+    STATE(22), 1,
+      aux_sym_junk_repeat1,
+    ACTIONS(113), 3,
+      ts_builtin_sym_end,
+      sym__newline,
+      sym_comment,
+  [430] = 5,
+    ACTIONS(53), 1,
+      anon_sym_DOTexport_name_length,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(118), 1,
+      sym_address,
+    STATE(53), 1,
+      sym_code_start_directive,
+    STATE(119), 1,
+      sym_export_name_length_directive,
+  [446] = 5,
+    ACTIONS(53), 1,
+      anon_sym_DOTexport_name_length,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(118), 1,
+      sym_address,
+    STATE(44), 1,
+      sym_code_start_directive,
+    STATE(154), 1,
+      sym_export_name_length_directive,
+  [462] = 5,
+    ACTIONS(53), 1,
+      anon_sym_DOTexport_name_length,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(118), 1,
+      sym_address,
+    STATE(45), 1,
+      sym_code_start_directive,
+    STATE(139), 1,
+      sym_export_name_length_directive,
+  [478] = 1,
+    ACTIONS(120), 5,
+      ts_builtin_sym_end,
+      sym__newline,
+      anon_sym_COMMA,
+      anon_sym_BANG,
+      sym_comment,
+  [486] = 1,
+    ACTIONS(122), 5,
+      ts_builtin_sym_end,
+      sym__newline,
+      anon_sym_COMMA,
+      anon_sym_BANG,
+      sym_comment,
+  [494] = 1,
+    ACTIONS(103), 5,
+      ts_builtin_sym_end,
+      sym__newline,
+      anon_sym_COMMA,
+      anon_sym_BANG,
+      sym_comment,
+  [502] = 3,
+    ACTIONS(126), 1,
+      anon_sym_BSLASH,
+    STATE(33), 1,
+      aux_sym_junk_repeat1,
+    ACTIONS(124), 3,
+      ts_builtin_sym_end,
+      sym__newline,
+      sym_comment,
+  [514] = 5,
+    ACTIONS(53), 1,
+      anon_sym_DOTexport_name_length,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(118), 1,
+      sym_address,
+    STATE(64), 1,
+      sym_code_start_directive,
+    STATE(136), 1,
+      sym_export_name_length_directive,
+  [530] = 1,
+    ACTIONS(128), 5,
+      ts_builtin_sym_end,
+      sym__newline,
+      anon_sym_COMMA,
+      anon_sym_BANG,
+      sym_comment,
+  [538] = 1,
+    ACTIONS(130), 5,
+      ts_builtin_sym_end,
+      sym__newline,
+      anon_sym_COMMA,
+      anon_sym_BANG,
+      sym_comment,
+  [546] = 3,
+    ACTIONS(126), 1,
+      anon_sym_BSLASH,
+    STATE(22), 1,
+      aux_sym_junk_repeat1,
+    ACTIONS(132), 3,
+      ts_builtin_sym_end,
+      sym__newline,
+      sym_comment,
+  [558] = 1,
+    ACTIONS(134), 5,
+      ts_builtin_sym_end,
+      sym__newline,
+      anon_sym_COMMA,
+      anon_sym_BANG,
+      sym_comment,
+  [566] = 1,
+    ACTIONS(136), 5,
+      ts_builtin_sym_end,
+      sym__newline,
+      anon_sym_COMMA,
+      anon_sym_BANG,
+      sym_comment,
+  [574] = 1,
+    ACTIONS(138), 5,
+      ts_builtin_sym_end,
+      sym__newline,
+      anon_sym_COMMA,
+      anon_sym_BANG,
+      sym_comment,
+  [582] = 1,
+    ACTIONS(140), 4,
+      ts_builtin_sym_end,
+      sym__newline,
+      anon_sym_BSLASH,
+      sym_comment,
+  [589] = 4,
+    ACTIONS(142), 1,
+      sym__newline,
+    ACTIONS(144), 1,
+      anon_sym_stcm2,
+    ACTIONS(146), 1,
+      anon_sym_stcm2l,
+    STATE(147), 1,
+      sym_variant,
+  [602] = 4,
+    ACTIONS(144), 1,
+      anon_sym_stcm2,
+    ACTIONS(146), 1,
+      anon_sym_stcm2l,
+    ACTIONS(148), 1,
+      sym__newline,
+    STATE(125), 1,
+      sym_variant,
+  [615] = 3,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(150), 1,
+      sym_address,
+    STATE(65), 1,
+      sym_code_start_directive,
+  [625] = 3,
+    ACTIONS(152), 1,
+      anon_sym_DOTunk_header,
+    ACTIONS(154), 1,
+      anon_sym_DOTexport_name_length,
+    ACTIONS(156), 1,
+      anon_sym_DOTcode_start,
+  [635] = 3,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(150), 1,
+      sym_address,
+    STATE(53), 1,
+      sym_code_start_directive,
+  [645] = 2,
+    ACTIONS(81), 1,
+      sym_comment,
+    ACTIONS(79), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [653] = 3,
+    ACTIONS(158), 1,
+      ts_builtin_sym_end,
+    ACTIONS(160), 1,
+      sym__newline,
+    STATE(55), 1,
+      aux_sym_source_file_repeat2,
+  [663] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(162), 1,
+      ts_builtin_sym_end,
+    STATE(57), 1,
+      aux_sym_source_file_repeat2,
+  [673] = 3,
+    ACTIONS(164), 1,
+      anon_sym_DOTglobal_data,
+    ACTIONS(166), 1,
+      sym_address,
+    STATE(112), 1,
+      sym_global_data_directive,
+  [683] = 2,
+    ACTIONS(69), 1,
+      sym_comment,
+    ACTIONS(67), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [691] = 2,
+    ACTIONS(89), 1,
+      sym_comment,
+    ACTIONS(87), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [699] = 3,
+    ACTIONS(164), 1,
+      anon_sym_DOTglobal_data,
+    ACTIONS(166), 1,
+      sym_address,
+    STATE(111), 1,
+      sym_global_data_directive,
+  [709] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(168), 1,
+      ts_builtin_sym_end,
+    STATE(63), 1,
+      aux_sym_source_file_repeat2,
+  [719] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(168), 1,
+      ts_builtin_sym_end,
+    STATE(56), 1,
+      aux_sym_source_file_repeat2,
+  [729] = 2,
+    ACTIONS(172), 1,
+      sym_comment,
+    ACTIONS(170), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [737] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(174), 1,
+      ts_builtin_sym_end,
+    STATE(74), 1,
+      aux_sym_source_file_repeat2,
+  [747] = 3,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(150), 1,
+      sym_address,
+    STATE(64), 1,
+      sym_code_start_directive,
+  [757] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(162), 1,
+      ts_builtin_sym_end,
+    STATE(63), 1,
+      aux_sym_source_file_repeat2,
+  [767] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(174), 1,
+      ts_builtin_sym_end,
+    STATE(63), 1,
+      aux_sym_source_file_repeat2,
+  [777] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(176), 1,
+      ts_builtin_sym_end,
+    STATE(63), 1,
+      aux_sym_source_file_repeat2,
+  [787] = 2,
+    ACTIONS(93), 1,
+      sym_comment,
+    ACTIONS(91), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [795] = 2,
+    ACTIONS(180), 1,
+      sym_comment,
+    ACTIONS(178), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [803] = 2,
+    ACTIONS(184), 1,
+      sym_comment,
+    ACTIONS(182), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [811] = 3,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(150), 1,
+      sym_address,
+    STATE(45), 1,
+      sym_code_start_directive,
+  [821] = 3,
+    ACTIONS(5), 1,
+      anon_sym_DOTtag,
+    ACTIONS(7), 1,
+      sym_address,
+    STATE(104), 1,
+      sym_tag_directive,
+  [831] = 3,
+    ACTIONS(186), 1,
+      ts_builtin_sym_end,
+    ACTIONS(188), 1,
+      sym__newline,
+    STATE(63), 1,
+      aux_sym_source_file_repeat2,
+  [841] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(176), 1,
+      ts_builtin_sym_end,
+    STATE(72), 1,
+      aux_sym_source_file_repeat2,
+  [851] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(191), 1,
+      ts_builtin_sym_end,
+    STATE(73), 1,
+      aux_sym_source_file_repeat2,
+  [861] = 2,
+    ACTIONS(195), 1,
+      sym_comment,
+    ACTIONS(193), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [869] = 3,
+    ACTIONS(5), 1,
+      anon_sym_DOTtag,
+    ACTIONS(7), 1,
+      sym_address,
+    STATE(127), 1,
+      sym_tag_directive,
+  [879] = 3,
+    ACTIONS(164), 1,
+      anon_sym_DOTglobal_data,
+    ACTIONS(166), 1,
+      sym_address,
+    STATE(124), 1,
+      sym_global_data_directive,
+  [889] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(197), 1,
+      ts_builtin_sym_end,
+    STATE(50), 1,
+      aux_sym_source_file_repeat2,
+  [899] = 3,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(150), 1,
+      sym_address,
+    STATE(44), 1,
+      sym_code_start_directive,
+  [909] = 3,
+    ACTIONS(55), 1,
+      anon_sym_DOTcode_start,
+    ACTIONS(150), 1,
+      sym_address,
+    STATE(75), 1,
+      sym_code_start_directive,
+  [919] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(191), 1,
+      ts_builtin_sym_end,
+    STATE(63), 1,
+      aux_sym_source_file_repeat2,
+  [929] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(199), 1,
+      ts_builtin_sym_end,
+    STATE(63), 1,
+      aux_sym_source_file_repeat2,
+  [939] = 3,
+    ACTIONS(158), 1,
+      ts_builtin_sym_end,
+    ACTIONS(160), 1,
+      sym__newline,
+    STATE(63), 1,
+      aux_sym_source_file_repeat2,
+  [949] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(199), 1,
+      ts_builtin_sym_end,
+    STATE(76), 1,
+      aux_sym_source_file_repeat2,
+  [959] = 3,
+    ACTIONS(160), 1,
+      sym__newline,
+    ACTIONS(201), 1,
+      ts_builtin_sym_end,
+    STATE(63), 1,
+      aux_sym_source_file_repeat2,
+  [969] = 3,
+    ACTIONS(164), 1,
+      anon_sym_DOTglobal_data,
+    ACTIONS(166), 1,
+      sym_address,
+    STATE(117), 1,
+      sym_global_data_directive,
+  [979] = 1,
+    ACTIONS(203), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [984] = 1,
+    ACTIONS(205), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [989] = 1,
+    ACTIONS(207), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [994] = 1,
+    ACTIONS(209), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [999] = 1,
+    ACTIONS(79), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1004] = 1,
+    ACTIONS(211), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1009] = 1,
+    ACTIONS(213), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1014] = 1,
+    ACTIONS(215), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1019] = 1,
+    ACTIONS(67), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1024] = 1,
+    ACTIONS(87), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1029] = 2,
+    ACTIONS(217), 1,
+      sym__newline,
+    ACTIONS(219), 1,
+      sym_base64,
+  [1036] = 2,
+    ACTIONS(221), 1,
+      sym_hex_integer,
+    ACTIONS(223), 1,
+      sym_decimal,
+  [1043] = 2,
+    ACTIONS(225), 1,
+      sym_label,
+    ACTIONS(227), 1,
+      sym_global_data_offset,
+  [1050] = 1,
+    ACTIONS(170), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1055] = 1,
+    ACTIONS(91), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1060] = 1,
+    ACTIONS(178), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1065] = 2,
+    ACTIONS(154), 1,
+      anon_sym_DOTexport_name_length,
+    ACTIONS(156), 1,
+      anon_sym_DOTcode_start,
+  [1072] = 1,
+    ACTIONS(182), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1077] = 1,
+    ACTIONS(229), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1082] = 1,
+    ACTIONS(193), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1087] = 1,
+    ACTIONS(231), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1092] = 1,
+    ACTIONS(186), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1097] = 1,
+    ACTIONS(233), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1102] = 1,
+    ACTIONS(235), 2,
+      ts_builtin_sym_end,
+      sym__newline,
+  [1107] = 2,
+    ACTIONS(237), 1,
+      sym__newline,
+    ACTIONS(239), 1,
+      sym_base64,
+  [1114] = 1,
+    ACTIONS(241), 1,
+      sym__newline,
+  [1118] = 1,
+    ACTIONS(243), 1,
+      sym__newline,
+  [1122] = 1,
+    ACTIONS(245), 1,
+      sym__newline,
+  [1126] = 1,
+    ACTIONS(247), 1,
+      sym_string,
+  [1130] = 1,
+    ACTIONS(249), 1,
+      sym__newline,
+  [1134] = 1,
+    ACTIONS(251), 1,
+      sym__newline,
+  [1138] = 1,
+    ACTIONS(253), 1,
+      sym_base64,
+  [1142] = 1,
+    ACTIONS(255), 1,
+      sym_decimal,
+  [1146] = 1,
+    ACTIONS(257), 1,
+      sym__newline,
+  [1150] = 1,
+    ACTIONS(259), 1,
+      sym__newline,
+  [1154] = 1,
+    ACTIONS(261), 1,
+      sym__newline,
+  [1158] = 1,
+    ACTIONS(263), 1,
+      sym_decimal,
+  [1162] = 1,
+    ACTIONS(265), 1,
+      sym__newline,
+  [1166] = 1,
+    ACTIONS(267), 1,
+      anon_sym_DOTglobal_data,
+  [1170] = 1,
+    ACTIONS(269), 1,
+      sym__newline,
+  [1174] = 1,
+    ACTIONS(271), 1,
+      sym__newline,
+  [1178] = 1,
+    ACTIONS(273), 1,
+      sym__newline,
+  [1182] = 1,
+    ACTIONS(275), 1,
+      sym__newline,
+  [1186] = 1,
+    ACTIONS(277), 1,
+      sym__newline,
+  [1190] = 1,
+    ACTIONS(279), 1,
+      sym_base64,
+  [1194] = 1,
+    ACTIONS(281), 1,
+      sym__newline,
+  [1198] = 1,
+    ACTIONS(283), 1,
+      sym__newline,
+  [1202] = 1,
+    ACTIONS(142), 1,
+      sym__newline,
+  [1206] = 1,
+    ACTIONS(285), 1,
+      sym__newline,
+  [1210] = 1,
+    ACTIONS(287), 1,
+      sym__newline,
+  [1214] = 1,
+    ACTIONS(156), 1,
+      anon_sym_DOTcode_start,
+  [1218] = 1,
+    ACTIONS(289), 1,
+      sym_string,
+  [1222] = 1,
+    ACTIONS(291), 1,
+      anon_sym_RBRACK,
+  [1226] = 1,
+    ACTIONS(293), 1,
+      anon_sym_RBRACK,
+  [1230] = 1,
+    ACTIONS(295), 1,
+      sym_decimal,
+  [1234] = 1,
+    ACTIONS(297), 1,
+      sym__newline,
+  [1238] = 1,
+    ACTIONS(299), 1,
+      sym_decimal,
+  [1242] = 1,
+    ACTIONS(301), 1,
+      sym_decimal,
+  [1246] = 1,
+    ACTIONS(303), 1,
+      sym__newline,
+  [1250] = 1,
+    ACTIONS(305), 1,
+      sym_base64,
+  [1254] = 1,
+    ACTIONS(307), 1,
+      sym_string,
+  [1258] = 1,
+    ACTIONS(309), 1,
+      sym__newline,
+  [1262] = 1,
+    ACTIONS(311), 1,
+      sym_hex,
+  [1266] = 1,
+    ACTIONS(313), 1,
+      sym_label,
+  [1270] = 1,
+    ACTIONS(315), 1,
+      sym_decimal,
+  [1274] = 1,
+    ACTIONS(317), 1,
+      sym__newline,
+  [1278] = 1,
+    ACTIONS(319), 1,
+      sym_decimal,
+  [1282] = 1,
+    ACTIONS(321), 1,
+      sym_base64,
+  [1286] = 1,
+    ACTIONS(323), 1,
+      sym__newline,
+  [1290] = 1,
+    ACTIONS(325), 1,
+      sym__newline,
+  [1294] = 1,
+    ACTIONS(327), 1,
+      sym__newline,
+  [1298] = 1,
+    ACTIONS(329), 1,
+      sym__newline,
+  [1302] = 1,
+    ACTIONS(331), 1,
+      sym__newline,
+  [1306] = 1,
+    ACTIONS(237), 1,
+      sym__newline,
+  [1310] = 1,
+    ACTIONS(333), 1,
+      anon_sym_DOTtag,
+  [1314] = 1,
+    ACTIONS(335), 1,
+      ts_builtin_sym_end,
+  [1318] = 1,
+    ACTIONS(337), 1,
+      sym__newline,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(2)] = 0,
+  [SMALL_STATE(3)] = 41,
+  [SMALL_STATE(4)] = 72,
+  [SMALL_STATE(5)] = 96,
+  [SMALL_STATE(6)] = 118,
+  [SMALL_STATE(7)] = 140,
+  [SMALL_STATE(8)] = 162,
+  [SMALL_STATE(9)] = 182,
+  [SMALL_STATE(10)] = 202,
+  [SMALL_STATE(11)] = 224,
+  [SMALL_STATE(12)] = 244,
+  [SMALL_STATE(13)] = 264,
+  [SMALL_STATE(14)] = 284,
+  [SMALL_STATE(15)] = 304,
+  [SMALL_STATE(16)] = 324,
+  [SMALL_STATE(17)] = 344,
+  [SMALL_STATE(18)] = 363,
+  [SMALL_STATE(19)] = 378,
+  [SMALL_STATE(20)] = 393,
+  [SMALL_STATE(21)] = 406,
+  [SMALL_STATE(22)] = 418,
+  [SMALL_STATE(23)] = 430,
+  [SMALL_STATE(24)] = 446,
+  [SMALL_STATE(25)] = 462,
+  [SMALL_STATE(26)] = 478,
+  [SMALL_STATE(27)] = 486,
+  [SMALL_STATE(28)] = 494,
+  [SMALL_STATE(29)] = 502,
+  [SMALL_STATE(30)] = 514,
+  [SMALL_STATE(31)] = 530,
+  [SMALL_STATE(32)] = 538,
+  [SMALL_STATE(33)] = 546,
+  [SMALL_STATE(34)] = 558,
+  [SMALL_STATE(35)] = 566,
+  [SMALL_STATE(36)] = 574,
+  [SMALL_STATE(37)] = 582,
+  [SMALL_STATE(38)] = 589,
+  [SMALL_STATE(39)] = 602,
+  [SMALL_STATE(40)] = 615,
+  [SMALL_STATE(41)] = 625,
+  [SMALL_STATE(42)] = 635,
+  [SMALL_STATE(43)] = 645,
+  [SMALL_STATE(44)] = 653,
+  [SMALL_STATE(45)] = 663,
+  [SMALL_STATE(46)] = 673,
+  [SMALL_STATE(47)] = 683,
+  [SMALL_STATE(48)] = 691,
+  [SMALL_STATE(49)] = 699,
+  [SMALL_STATE(50)] = 709,
+  [SMALL_STATE(51)] = 719,
+  [SMALL_STATE(52)] = 729,
+  [SMALL_STATE(53)] = 737,
+  [SMALL_STATE(54)] = 747,
+  [SMALL_STATE(55)] = 757,
+  [SMALL_STATE(56)] = 767,
+  [SMALL_STATE(57)] = 777,
+  [SMALL_STATE(58)] = 787,
+  [SMALL_STATE(59)] = 795,
+  [SMALL_STATE(60)] = 803,
+  [SMALL_STATE(61)] = 811,
+  [SMALL_STATE(62)] = 821,
+  [SMALL_STATE(63)] = 831,
+  [SMALL_STATE(64)] = 841,
+  [SMALL_STATE(65)] = 851,
+  [SMALL_STATE(66)] = 861,
+  [SMALL_STATE(67)] = 869,
+  [SMALL_STATE(68)] = 879,
+  [SMALL_STATE(69)] = 889,
+  [SMALL_STATE(70)] = 899,
+  [SMALL_STATE(71)] = 909,
+  [SMALL_STATE(72)] = 919,
+  [SMALL_STATE(73)] = 929,
+  [SMALL_STATE(74)] = 939,
+  [SMALL_STATE(75)] = 949,
+  [SMALL_STATE(76)] = 959,
+  [SMALL_STATE(77)] = 969,
+  [SMALL_STATE(78)] = 979,
+  [SMALL_STATE(79)] = 984,
+  [SMALL_STATE(80)] = 989,
+  [SMALL_STATE(81)] = 994,
+  [SMALL_STATE(82)] = 999,
+  [SMALL_STATE(83)] = 1004,
+  [SMALL_STATE(84)] = 1009,
+  [SMALL_STATE(85)] = 1014,
+  [SMALL_STATE(86)] = 1019,
+  [SMALL_STATE(87)] = 1024,
+  [SMALL_STATE(88)] = 1029,
+  [SMALL_STATE(89)] = 1036,
+  [SMALL_STATE(90)] = 1043,
+  [SMALL_STATE(91)] = 1050,
+  [SMALL_STATE(92)] = 1055,
+  [SMALL_STATE(93)] = 1060,
+  [SMALL_STATE(94)] = 1065,
+  [SMALL_STATE(95)] = 1072,
+  [SMALL_STATE(96)] = 1077,
+  [SMALL_STATE(97)] = 1082,
+  [SMALL_STATE(98)] = 1087,
+  [SMALL_STATE(99)] = 1092,
+  [SMALL_STATE(100)] = 1097,
+  [SMALL_STATE(101)] = 1102,
+  [SMALL_STATE(102)] = 1107,
+  [SMALL_STATE(103)] = 1114,
+  [SMALL_STATE(104)] = 1118,
+  [SMALL_STATE(105)] = 1122,
+  [SMALL_STATE(106)] = 1126,
+  [SMALL_STATE(107)] = 1130,
+  [SMALL_STATE(108)] = 1134,
+  [SMALL_STATE(109)] = 1138,
+  [SMALL_STATE(110)] = 1142,
+  [SMALL_STATE(111)] = 1146,
+  [SMALL_STATE(112)] = 1150,
+  [SMALL_STATE(113)] = 1154,
+  [SMALL_STATE(114)] = 1158,
+  [SMALL_STATE(115)] = 1162,
+  [SMALL_STATE(116)] = 1166,
+  [SMALL_STATE(117)] = 1170,
+  [SMALL_STATE(118)] = 1174,
+  [SMALL_STATE(119)] = 1178,
+  [SMALL_STATE(120)] = 1182,
+  [SMALL_STATE(121)] = 1186,
+  [SMALL_STATE(122)] = 1190,
+  [SMALL_STATE(123)] = 1194,
+  [SMALL_STATE(124)] = 1198,
+  [SMALL_STATE(125)] = 1202,
+  [SMALL_STATE(126)] = 1206,
+  [SMALL_STATE(127)] = 1210,
+  [SMALL_STATE(128)] = 1214,
+  [SMALL_STATE(129)] = 1218,
+  [SMALL_STATE(130)] = 1222,
+  [SMALL_STATE(131)] = 1226,
+  [SMALL_STATE(132)] = 1230,
+  [SMALL_STATE(133)] = 1234,
+  [SMALL_STATE(134)] = 1238,
+  [SMALL_STATE(135)] = 1242,
+  [SMALL_STATE(136)] = 1246,
+  [SMALL_STATE(137)] = 1250,
+  [SMALL_STATE(138)] = 1254,
+  [SMALL_STATE(139)] = 1258,
+  [SMALL_STATE(140)] = 1262,
+  [SMALL_STATE(141)] = 1266,
+  [SMALL_STATE(142)] = 1270,
+  [SMALL_STATE(143)] = 1274,
+  [SMALL_STATE(144)] = 1278,
+  [SMALL_STATE(145)] = 1282,
+  [SMALL_STATE(146)] = 1286,
+  [SMALL_STATE(147)] = 1290,
+  [SMALL_STATE(148)] = 1294,
+  [SMALL_STATE(149)] = 1298,
+  [SMALL_STATE(150)] = 1302,
+  [SMALL_STATE(151)] = 1306,
+  [SMALL_STATE(152)] = 1310,
+  [SMALL_STATE(153)] = 1314,
+  [SMALL_STATE(154)] = 1318,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [11] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 1, 0, 0),
+  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(129),
+  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(132),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(134),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(18),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(140),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(141),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(8),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [41] = {.entry = {.count = 1, .reusable = false}}, SHIFT(36),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(114),
+  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(142),
+  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(12),
+  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [59] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 1, 0, 1),
+  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [65] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [67] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 3, 0, 2),
+  [69] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [71] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 2, 0, 3),
+  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [75] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 2, 0, 2),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [79] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 2, 0, 1),
+  [81] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [83] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 3, 0, 4),
+  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [87] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 3, 0, 3),
+  [89] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [91] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 4, 0, 4),
+  [93] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [99] = {.entry = {.count = 1, .reusable = false}}, SHIFT(11),
+  [101] = {.entry = {.count = 1, .reusable = false}}, SHIFT(14),
+  [103] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_action_repeat1, 2, 0, 0),
+  [105] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_action_repeat1, 2, 0, 0), SHIFT_REPEAT(3),
+  [108] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(21),
+  [111] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
+  [113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_junk_repeat1, 2, 0, 0),
+  [115] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_junk_repeat1, 2, 0, 0), SHIFT_REPEAT(133),
+  [118] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [120] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_op, 2, 0, 0),
+  [122] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call, 2, 0, 0),
+  [124] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_junk, 2, 0, 0),
+  [126] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [128] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_integer, 2, 0, 0),
+  [130] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_value, 2, 0, 0),
+  [132] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_junk, 3, 0, 0),
+  [134] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action_ref, 3, 0, 0),
+  [136] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_data_ref, 3, 0, 0),
+  [138] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_value, 1, 0, 0),
+  [140] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_junk_repeat1, 3, 0, 0),
+  [142] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tag_directive, 3, 0, 0),
+  [144] = {.entry = {.count = 1, .reusable = false}}, SHIFT(108),
+  [146] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [148] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tag_directive, 2, 0, 0),
+  [150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [158] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 8, 0, 0),
+  [160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [162] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 9, 0, 0),
+  [164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [168] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 6, 0, 0),
+  [170] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 3, 0, 1),
+  [172] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [174] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 7, 0, 0),
+  [176] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 10, 0, 0),
+  [178] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 4, 0, 2),
+  [180] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [182] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 4, 0, 3),
+  [184] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [186] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2, 0, 0),
+  [188] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2, 0, 0), SHIFT_REPEAT(2),
+  [191] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 11, 0, 0),
+  [193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 5, 0, 4),
+  [195] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [197] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 5, 0, 0),
+  [199] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 12, 0, 0),
+  [201] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 13, 0, 0),
+  [203] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 5, 0, 2),
+  [205] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_directive, 2, 0, 0),
+  [207] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_padding_directive, 2, 0, 0),
+  [209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_align_directive, 2, 0, 0),
+  [211] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_code_start_directive, 2, 0, 0),
+  [213] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_padding_directive, 3, 0, 0),
+  [215] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_align_directive, 3, 0, 0),
+  [217] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_data_directive, 1, 0, 0),
+  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [223] = {.entry = {.count = 1, .reusable = false}}, SHIFT(31),
+  [225] = {.entry = {.count = 1, .reusable = false}}, SHIFT(130),
+  [227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [229] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 4, 0, 1),
+  [231] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 5, 0, 3),
+  [233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_code_start_directive, 1, 0, 0),
+  [235] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 6, 0, 4),
+  [237] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_data_directive, 2, 0, 0),
+  [239] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [241] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unk_header_directive, 3, 0, 0),
+  [243] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unk_header_directive, 2, 0, 0),
+  [247] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [249] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_export_name_length_directive, 2, 0, 0),
+  [251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variant, 1, 0, 0),
+  [253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [255] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [257] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [259] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [261] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [263] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [265] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [267] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [269] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [273] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [275] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_data_directive, 3, 0, 0),
+  [277] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [279] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_export_name_length_directive, 3, 0, 0),
+  [283] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [285] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [289] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [297] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [299] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [303] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [307] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [309] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [311] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [313] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [315] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [317] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [319] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [323] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [325] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tag_directive, 4, 0, 0),
+  [327] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [329] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [331] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [333] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [335] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [337] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_stcm2(void) {
+  static const TSLanguage language = {
+    .abi_version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .supertype_count = SUPERTYPE_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = (const void*)ts_lex_modes,
+    .lex_fn = ts_lex,
+    .primary_state_ids = ts_primary_state_ids,
+    .name = "stcm2",
+    .max_reserved_word_set_size = 0,
+    .metadata = {
+      .major_version = 0,
+      .minor_version = 0,
+      .patch_version = 0,
+    },
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif